@@ -0,0 +1,305 @@
+use std::{fs, path::Path, time::Duration};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{
+    params::Params,
+    solver_types::{solve, Parts, Solution, SolveReport},
+};
+
+/// Summary statistics for repeated timings of one phase, in milliseconds
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub p95: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// summarise a set of timings. Panics if there are no timings
+    pub fn from_durations(durations: &[Duration]) -> Self {
+        let mut ms: Vec<f64> = durations.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        ms.sort_by(|a, b| a.total_cmp(b));
+
+        let n = ms.len();
+        let mean = ms.iter().sum::<f64>() / n as f64;
+        let median = if n.is_multiple_of(2) {
+            (ms[n / 2 - 1] + ms[n / 2]) / 2.0
+        } else {
+            ms[n / 2]
+        };
+        // nearest-rank percentile
+        let p95 = ms[((n as f64 * 0.95).ceil() as usize).max(1) - 1];
+        let variance = match n {
+            1 => 0.0,
+            _ => ms.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64,
+        };
+
+        Self {
+            min: ms[0],
+            median,
+            mean,
+            p95,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Timing statistics for every phase of a benchmarked day
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct BenchReport {
+    pub day: usize,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part_1: Stats,
+    pub part_2: Stats,
+    pub total: Stats,
+}
+
+/// Repeatedly solve some or all of a day, discarding the first `warmup` runs.
+/// Every run re-parses the input, so parts that mutate their input can't affect later runs
+pub fn bench(
+    day: usize,
+    solution: &dyn Solution,
+    parts: Parts,
+    params: &Params,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<BenchReport> {
+    if iterations == 0 {
+        return Err(anyhow!("Benchmarking requires at least one iteration"));
+    }
+
+    for _ in 0..warmup {
+        solve(solution, input, parts, params)?;
+    }
+
+    let reports = (0..iterations)
+        .map(|_| solve(solution, input, parts, params))
+        .collect::<Result<Vec<_>>>()?;
+
+    let phase = |get: fn(&SolveReport) -> Duration| {
+        Stats::from_durations(&reports.iter().map(get).collect::<Vec<_>>())
+    };
+
+    Ok(BenchReport {
+        day,
+        iterations,
+        parse: phase(|r| r.parse_time),
+        part_1: phase(|r| r.part_1_time),
+        part_2: phase(|r| r.part_2_time),
+        total: phase(SolveReport::total_time),
+    })
+}
+
+/// A year's benchmark reports, saved to compare later runs against
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct Baseline {
+    year: usize,
+    reports: Vec<BenchReport>,
+}
+
+/// Save a year's benchmark reports as a baseline to compare later runs against
+pub fn save_baseline(file_path: &Path, year: usize, reports: &[BenchReport]) -> Result<()> {
+    let json = serde_json::to_string_pretty(&Baseline {
+        year,
+        reports: reports.to_vec(),
+    })?;
+
+    fs::write(file_path, json).map_err(|error| {
+        anyhow!(
+            "Failed to write baseline '{}'. Reason: {}",
+            file_path.display(),
+            error
+        )
+    })
+}
+
+/// Load benchmark reports previously saved with `save_baseline`, which must be for the same `year`
+pub fn load_baseline(file_path: &Path, year: usize) -> Result<Vec<BenchReport>> {
+    let text = fs::read_to_string(file_path).map_err(|error| {
+        anyhow!(
+            "Failed to read baseline '{}'. Reason: {}",
+            file_path.display(),
+            error
+        )
+    })?;
+
+    let baseline: Baseline = serde_json::from_str(&text)
+        .map_err(|error| anyhow!("Invalid baseline file. Reason: {}", error))?;
+
+    if baseline.year != year {
+        return Err(anyhow!(
+            "Baseline '{}' is for {}, not {}",
+            file_path.display(),
+            baseline.year,
+            year
+        ));
+    }
+
+    Ok(baseline.reports)
+}
+
+/// The change in a day's median total time between a baseline and a new run
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: usize,
+    pub baseline_ms: f64,
+    pub current_ms: f64,
+    pub delta_ms: f64,
+    pub change_percent: f64,
+    pub regression: bool,
+}
+
+/// Compare median total times against a baseline.
+/// A day regresses when it is more than `threshold_percent` slower. Days missing from the baseline are skipped
+pub fn compare(
+    baseline: &[BenchReport],
+    current: &[BenchReport],
+    threshold_percent: f64,
+) -> Vec<Comparison> {
+    current
+        .iter()
+        .filter_map(|now| {
+            let before = baseline.iter().find(|b| b.day == now.day)?;
+            let (baseline_ms, current_ms) = (before.total.median, now.total.median);
+            let delta_ms = current_ms - baseline_ms;
+            let change_percent = match baseline_ms {
+                b if b > 0.0 => delta_ms / b * 100.0,
+                _ => 0.0,
+            };
+
+            Some(Comparison {
+                day: now.day,
+                baseline_ms,
+                current_ms,
+                delta_ms,
+                change_percent,
+                regression: change_percent > threshold_percent,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, time::Duration};
+
+    use super::{bench, compare, load_baseline, save_baseline, BenchReport, Stats};
+    use crate::solutions::templates::linear_template::ExampleSolutionLinear;
+    use crate::utils::{
+        params::Params,
+        solver_types::{linear, Parts},
+    };
+
+    #[test]
+    fn test_stats() {
+        let durations = [5, 1, 4, 2, 3]
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect::<Vec<_>>();
+
+        let stats = Stats::from_durations(&durations);
+
+        assert_eq!(1.0, stats.min);
+        assert_eq!(3.0, stats.median);
+        assert_eq!(3.0, stats.mean);
+        assert_eq!(5.0, stats.p95);
+        assert!((stats.stddev - 2.5f64.sqrt()).abs() < 1e-9);
+
+        let single = Stats::from_durations(&[Duration::from_millis(2)]);
+        assert_eq!(2.0, single.p95);
+        assert_eq!(0.0, single.stddev);
+    }
+
+    #[test]
+    fn test_bench() {
+        let report = bench(
+            3,
+            &linear::<ExampleSolutionLinear, _, _, _>(),
+            Parts::Both,
+            &Params::default(),
+            "[1,2,3]",
+            2,
+            10,
+        )
+        .unwrap();
+
+        assert_eq!(3, report.day);
+        assert_eq!(10, report.iterations);
+        assert!(report.total.min <= report.total.median);
+        assert!(report.total.median <= report.total.p95);
+
+        assert!(bench(
+            3,
+            &linear::<ExampleSolutionLinear, _, _, _>(),
+            Parts::One,
+            &Params::default(),
+            "[1]",
+            0,
+            0
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let with_median = |day, median| BenchReport {
+            day,
+            total: Stats {
+                median,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let baseline = vec![with_median(1, 10.0), with_median(2, 10.0)];
+        let current = vec![
+            with_median(1, 12.0),
+            with_median(2, 10.5),
+            with_median(3, 1.0),
+        ];
+
+        let result = compare(&baseline, &current, 10.0);
+
+        assert_eq!(2, result.len());
+        assert_eq!(
+            (2.0, 20.0, true),
+            (
+                result[0].delta_ms,
+                result[0].change_percent,
+                result[0].regression
+            )
+        );
+        assert_eq!(
+            (0.5, 5.0, false),
+            (
+                result[1].delta_ms,
+                result[1].change_percent,
+                result[1].regression
+            )
+        );
+    }
+
+    #[test]
+    fn test_baseline() {
+        let path = env::temp_dir().join("aoc2022_baseline.json");
+        let reports = vec![BenchReport {
+            day: 4,
+            iterations: 10,
+            ..Default::default()
+        }];
+
+        save_baseline(&path, 2022, &reports).unwrap();
+
+        assert_eq!(reports, load_baseline(&path, 2022).unwrap());
+        assert_eq!(
+            format!("Baseline '{}' is for 2022, not 2023", path.display()),
+            load_baseline(&path, 2023).unwrap_err().to_string()
+        );
+    }
+}
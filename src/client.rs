@@ -0,0 +1,275 @@
+use std::{env, fs, path::Path};
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+
+/// Where puzzle inputs are downloaded from, unless overridden
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable holding the session cookie of a logged-in AoC account
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Environment variable overriding the base URL, e.g. to point at a local test server
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Default location of the client config file
+pub const DEFAULT_CONFIG_PATH: &str = "./aoc.toml";
+
+const USER_AGENT: &str = concat!(
+    "github.com/RyanRMurray/AOC2022 aoc2022/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Optional settings for talking to AoC, read from a toml file
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct ClientConfig {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl ClientConfig {
+    /// Load the config file, or use an empty config if it doesn't exist
+    pub fn load(file_path: &Path) -> Result<Self> {
+        if !file_path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = fs::read_to_string(file_path).map_err(|error| {
+            anyhow!(
+                "Failed to read config file '{}'. Reason: {}",
+                file_path.display(),
+                error
+            )
+        })?;
+
+        toml::from_str(&text).map_err(|error| anyhow!("Invalid config file. Reason: {}", error))
+    }
+}
+
+/// A client for an AoC-compatible server
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Create a client, taking each setting from the first of: the override, the environment, the config file.
+    /// The base URL falls back to the real AoC site
+    pub fn from_config(config_path: &Path, base_url_override: Option<&str>) -> Result<Self> {
+        let config = ClientConfig::load(config_path)?;
+
+        let session = env::var(SESSION_ENV)
+            .ok()
+            .or(config.session)
+            .ok_or_else(|| {
+                anyhow!(
+                    "No session token found. Set {} or add `session = \"...\"` to '{}'",
+                    SESSION_ENV,
+                    config_path.display()
+                )
+            })?;
+
+        let base_url = base_url_override
+            .map(str::to_string)
+            .or_else(|| env::var(BASE_URL_ENV).ok())
+            .or(config.base_url)
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+
+        Ok(Self::new(&base_url, &session))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Send a request, turning error statuses into readable errors
+    pub(crate) fn send(
+        &self,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String> {
+        let request = request.set("Cookie", &format!("session={}", self.session));
+
+        let response = match form {
+            None => request.call(),
+            Some(form) => request.send_form(form),
+        };
+
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(code, response)) => Err(anyhow!(
+                "Server responded with {}: {}",
+                code,
+                response.into_string().unwrap_or_default().trim()
+            )),
+            Err(error) => Err(anyhow!("Request failed. Reason: {}", error)),
+        }
+    }
+
+    pub(crate) fn day_url(&self, year: usize, day: usize) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    /// Download a day's puzzle input
+    pub fn fetch_input(&self, year: usize, day: usize) -> Result<String> {
+        let url = format!("{}/input", self.day_url(year, day));
+
+        self.send(self.agent.get(&url), None)
+    }
+
+    /// Submit an answer for one part of a day, returning the server's response page
+    pub fn submit_answer(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        answer: &str,
+    ) -> Result<String> {
+        let url = format!("{}/answer", self.day_url(year, day));
+        let level = part.to_string();
+
+        self.send(
+            self.agent.post(&url),
+            Some(&[("level", &level), ("answer", answer)]),
+        )
+    }
+}
+
+/// Download a day's input to the given path, unless it's already there.
+/// Returns true if the input was downloaded
+pub fn fetch_to_cache(
+    client: &AocClient,
+    year: usize,
+    day: usize,
+    file_path: &Path,
+    force: bool,
+) -> Result<bool> {
+    if file_path.exists() && !force {
+        return Ok(false);
+    }
+
+    let input = client.fetch_input(year, day)?;
+
+    if let Some(dir) = file_path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(file_path, input).map_err(|error| {
+        anyhow!(
+            "Failed to write input to '{}'. Reason: {}",
+            file_path.display(),
+            error
+        )
+    })?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        env, fs,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::{fetch_to_cache, AocClient};
+
+    /// Serve a fixed response to the next `requests` requests on a local port.
+    /// Returns the base URL and a channel receiving each raw request
+    pub(crate) fn stub_server(
+        requests: usize,
+        status: u16,
+        body: &'static str,
+    ) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} STUB\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                sender.send(request).unwrap();
+            }
+        });
+
+        (url, receiver)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (url, requests) = stub_server(1, 200, "1000\n2000\n");
+        let client = AocClient::new(&url, "abc123\n");
+
+        assert_eq!("1000\n2000\n", client.fetch_input(2022, 7).unwrap());
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2022/day/7/input HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request.contains("User-Agent: github.com/RyanRMurray/AOC2022"));
+    }
+
+    #[test]
+    fn test_fetch_error() {
+        let (url, _) = stub_server(1, 400, "Puzzle inputs differ by user.");
+        let client = AocClient::new(&url, "expired");
+
+        let err = client.fetch_input(2022, 1).unwrap_err().to_string();
+        assert_eq!(
+            "Server responded with 400: Puzzle inputs differ by user.",
+            err
+        );
+    }
+
+    #[test]
+    fn test_fetch_to_cache() {
+        let (url, requests) = stub_server(1, 200, "A Y\n");
+        let client = AocClient::new(&url, "abc123");
+        let cache_path = env::temp_dir()
+            .join("aoc2022_client_cache")
+            .join("input_02.txt");
+        let _ = fs::remove_file(&cache_path);
+
+        assert!(fetch_to_cache(&client, 2022, 2, &cache_path, false).unwrap());
+        assert_eq!("A Y\n", fs::read_to_string(&cache_path).unwrap());
+
+        // already cached, so the server isn't asked again
+        assert!(!fetch_to_cache(&client, 2022, 2, &cache_path, false).unwrap());
+        assert_eq!(1, requests.try_iter().count());
+    }
+}
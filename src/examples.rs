@@ -0,0 +1,236 @@
+//! Each day's example inputs are stored as `{examples_dir}/{year}/dayNN/{name}.txt`.
+//! The answers they should give, and any params they need, go in `answers.toml` in the same directory, in a table per example
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use serde::Deserialize;
+
+use crate::utils::{
+    answers::{DayAnswers, Verdict},
+    params::Params,
+    solver_types::{solve, Parts, Solution, SolveReport},
+};
+
+/// Default location of the examples, relative to the repo root
+pub const DEFAULT_EXAMPLES_DIR: &str = "./puzzle_examples";
+
+/// An example's table in `answers.toml`
+#[derive(Deserialize, Debug, Default)]
+struct ExampleEntry {
+    #[serde(flatten)]
+    answers: DayAnswers,
+    #[serde(default)]
+    params: HashMap<String, toml::Value>,
+}
+
+/// One of a day's example inputs, with the answers it should give
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: DayAnswers,
+    pub params: Params,
+}
+
+impl Example {
+    /// the parts this example has answers for. An example without any answers solves both
+    pub fn parts(&self) -> Parts {
+        match self.answers.get() {
+            (Some(_), None) => Parts::One,
+            (None, Some(_)) => Parts::Two,
+            _ => Parts::Both,
+        }
+    }
+}
+
+/// The directory holding a day's examples
+pub fn example_dir(examples_dir: &Path, year: usize, day: usize) -> PathBuf {
+    examples_dir
+        .join(year.to_string())
+        .join(format!("day{:02}", day))
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|error| {
+        anyhow!(
+            "Failed to read example '{}'. Reason: {}",
+            path.display(),
+            error
+        )
+    })
+}
+
+/// params are written as toml values, so `target = 10` doesn't need quoting
+fn param_value(value: toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s,
+        value => value.to_string(),
+    }
+}
+
+/// Load all of a day's examples, ordered by name. A day without an examples directory has none
+pub fn load_examples(examples_dir: &Path, year: usize, day: usize) -> Result<Vec<Example>> {
+    let dir = example_dir(examples_dir, year, day);
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let answers_path = dir.join("answers.toml");
+    let mut entries: HashMap<String, ExampleEntry> = match answers_path.is_file() {
+        true => toml::from_str(&read(&answers_path)?).map_err(|error| {
+            anyhow!(
+                "Invalid example answers '{}'. Reason: {}",
+                answers_path.display(),
+                error
+            )
+        })?,
+        false => HashMap::new(),
+    };
+
+    let inputs = fs::read_dir(&dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .sorted()
+        .collect::<Vec<_>>();
+
+    let mut examples = vec![];
+
+    for path in inputs {
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| anyhow!("Invalid example name '{}'", path.display()))?
+            .to_string();
+        let entry = entries.remove(&name).unwrap_or_default();
+
+        examples.push(Example {
+            input: read(&path)?,
+            answers: entry.answers,
+            params: Params(
+                entry
+                    .params
+                    .into_iter()
+                    .map(|(name, value)| (name, param_value(value)))
+                    .collect(),
+            ),
+            name,
+        });
+    }
+
+    // answers without an input are most likely a typo in the file name
+    if let Some(name) = entries.keys().sorted().next() {
+        return Err(anyhow!(
+            "Example '{}' has answers but no input. Expected '{}'",
+            name,
+            dir.join(format!("{}.txt", name)).display()
+        ));
+    }
+
+    Ok(examples)
+}
+
+/// Solve some or all of an example with a day's solution, and check the answers it gives
+pub fn solve_example(
+    solution: &dyn Solution,
+    example: &Example,
+    parts: Parts,
+) -> Result<(SolveReport, (Verdict, Verdict))> {
+    let report = solve(solution, &example.input, parts, &example.params)?;
+    let verdicts = example.answers.verify(&report);
+
+    Ok((report, verdicts))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path};
+
+    use super::{load_examples, solve_example};
+    use crate::{
+        registry,
+        utils::{answers::Verdict, solver_types::Parts},
+    };
+
+    #[test]
+    fn test_load_examples() {
+        let dir = env::temp_dir().join("aoc2022_examples");
+        let day_dir = dir.join("2022").join("day09");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&day_dir).unwrap();
+
+        fs::write(day_dir.join("example.txt"), "R 4").unwrap();
+        fs::write(day_dir.join("larger.txt"), "R 5").unwrap();
+        fs::write(day_dir.join("notes.md"), "not an example").unwrap();
+        fs::write(
+            day_dir.join("answers.toml"),
+            "[example]\npart1 = 4\n\n[larger]\npart2 = \"1\"\nparams = { knots = 10 }\n",
+        )
+        .unwrap();
+
+        let examples = load_examples(&dir, 2022, 9).unwrap();
+
+        assert_eq!(
+            vec!["example", "larger"],
+            examples.iter().map(|e| &e.name).collect::<Vec<_>>()
+        );
+        assert_eq!("R 4", examples[0].input);
+        assert_eq!(Parts::One, examples[0].parts());
+        assert_eq!(Parts::Two, examples[1].parts());
+        assert_eq!(Some(&"10".to_string()), examples[1].params.0.get("knots"));
+
+        assert!(load_examples(&dir, 2022, 10).unwrap().is_empty());
+
+        fs::write(day_dir.join("answers.toml"), "[exmaple]\npart1 = 4\n").unwrap();
+        assert!(load_examples(&dir, 2022, 9).is_err());
+    }
+
+    /// every registered day is checked against all of its examples
+    #[test]
+    fn test_every_example() {
+        let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("puzzle_examples");
+
+        for year in registry::years() {
+            for registration in registry::solutions(year) {
+                let day = registration.day;
+                let examples = load_examples(&examples_dir, year, day).unwrap();
+                assert!(
+                    !examples.is_empty(),
+                    "day {} of {} has no examples",
+                    day,
+                    year
+                );
+
+                for example in examples {
+                    let (_, verdicts) =
+                        solve_example(registration.solution, &example, example.parts())
+                            .unwrap_or_else(|err| {
+                                panic!(
+                                    "day {} of {}, example '{}' failed: {}",
+                                    day, year, example.name, err
+                                )
+                            });
+
+                    for (part, verdict) in [(1, verdicts.0), (2, verdicts.1)] {
+                        assert!(
+                            matches!(verdict, Verdict::Pass | Verdict::Skipped),
+                            "day {} of {}, example '{}' part {}: {}",
+                            day,
+                            year,
+                            example.name,
+                            part,
+                            verdict
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
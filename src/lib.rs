@@ -0,0 +1,72 @@
+//! Advent of Code solutions, along with the utilities and harness they're built on.
+//!
+//! The parts meant for reuse are:
+//!  - [`utils`]: [`Grid`], [`Pt`], input loaders, and the [`SolutionLinear`]/[`SolutionSimultaneous`] traits a day implements
+//!  - [`registry`]: the solutions registered by each `solutions::yYYYY::dayNN` module
+//!  - [`runner`]: loading inputs and running a registered day, with panics and timeouts caught
+//!  - [`modes`]: everything the command line does - solving, verifying, benchmarking and checking examples for a set of days, configured by a [`modes::RunConfig`]
+//!
+//! The rest is private, apart from the handful of items the command line also needs, which are re-exported here.
+//!
+//! A day implements one of the solver traits, and is solved with the matching `solve_` function.
+//! The `linear` and `simultaneous` adapters turn either kind into a [`Solution`], which can also solve a single part:
+//!
+//! ```
+//! use aoc2022::{solve_linear, SolutionLinear};
+//!
+//! struct Sum {}
+//!
+//! impl SolutionLinear<Vec<u32>, u32, u32> for Sum {
+//!     fn load(input: &str) -> anyhow::Result<Vec<u32>> {
+//!         Ok(input.lines().map(|l| l.parse().unwrap()).collect())
+//!     }
+//!
+//!     fn part1(input: &mut Vec<u32>) -> anyhow::Result<u32> {
+//!         Ok(input.iter().sum())
+//!     }
+//!
+//!     fn part2(input: &mut Vec<u32>, part_1_solution: u32) -> anyhow::Result<u32> {
+//!         Ok(part_1_solution * input.len() as u32)
+//!     }
+//! }
+//!
+//! let report = solve_linear::<Sum, _, _, _>("1\n2\n3").unwrap();
+//! assert_eq!(Some("6".to_string()), report.part_1);
+//! assert_eq!(Some("18".to_string()), report.part_2);
+//!
+//! use aoc2022::utils::{params::Params, solver_types::{linear, solve, Parts}};
+//!
+//! let report = solve(&linear::<Sum, _, _, _>(), "1\n2\n3", Parts::Two, &Params::default()).unwrap();
+//! assert_eq!(None, report.part_1);
+//! assert_eq!(Some("18".to_string()), report.part_2);
+//! ```
+
+mod bench;
+mod client;
+mod examples;
+pub mod modes;
+mod output;
+mod parallel;
+pub mod registry;
+pub mod runner;
+mod scaffold;
+mod selection;
+mod solutions;
+mod submit;
+pub mod utils;
+
+pub use client::DEFAULT_CONFIG_PATH;
+pub use examples::{example_dir, DEFAULT_EXAMPLES_DIR};
+pub use output::OutputFormat;
+pub use scaffold::{scaffold, DEFAULT_SOLUTIONS_DIR};
+pub use selection::{parse_day, DaySet, DEFAULT_ANSWERS_PATTERN, DEFAULT_INPUT_PATTERN};
+pub use submit::DEFAULT_HISTORY_PATH;
+pub use utils::{
+    dense_grid::DenseGrid,
+    grid::{Grid, GridLike},
+    point::Pt,
+    solver_types::{
+        solve_linear, solve_simultaneous, Solution, SolutionLinear, SolutionSimultaneous,
+        SolveReport,
+    },
+};
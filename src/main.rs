@@ -1,137 +1,346 @@
-use std::{fs, path::Path};
+use std::{path::Path, path::PathBuf, process::ExitCode, time::Duration};
 
 use anyhow::{anyhow, Ok, Result};
-use clap::{Parser, ValueEnum};
-use solutions::SOLUTIONS;
+use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::{
-    solutions::templates::{
-        linear_template::ExampleSolutionLinear, simultaneous_template::ExampleSolutionSimultaneous,
+use aoc2022::{
+    example_dir,
+    modes::{
+        confirm, fetch_missing_inputs, run_all, run_bench, run_day, run_example, run_examples,
+        run_fetch, run_submit, run_verify, BenchConfig, RunConfig,
     },
-    utils::solver_types::{solve_linear, solve_simultaneous},
+    parse_day,
+    registry::{self, SolutionKind},
+    runner::parse_timeout,
+    scaffold,
+    utils::{
+        params::{parse_pair, Params},
+        solver_types::Parts,
+    },
+    DaySet, OutputFormat, DEFAULT_ANSWERS_PATTERN, DEFAULT_CONFIG_PATH, DEFAULT_EXAMPLES_DIR,
+    DEFAULT_HISTORY_PATH, DEFAULT_INPUT_PATTERN, DEFAULT_SOLUTIONS_DIR,
 };
 
-pub mod solutions;
-pub mod utils;
-
 #[derive(Debug, Clone, ValueEnum)]
 enum RunMode {
     Example,
     Single,
     All,
+    Verify,
+    Bench,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Download puzzle inputs to the location given by --input-pattern
+    Fetch {
+        /// Specify which day's input to download
+        #[arg(long, short, required_unless_present = "days", value_parser = parse_day)]
+        day: Option<usize>,
+        /// Specify a set of days to download, e.g. 3,7,10-14
+        #[arg(long, conflicts_with = "day")]
+        days: Option<DaySet>,
+        /// Download the input even if it's already been downloaded
+        #[arg(long)]
+        force: bool,
+    },
+    /// Solve a day and submit one part's answer, refusing answers already known to be wrong
+    Submit {
+        /// Specify which day's answer to submit
+        #[arg(long, short, value_parser = parse_day)]
+        day: usize,
+        /// Specify which part's answer to submit
+        #[arg(long, short, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Specify the filepath to the history of submitted answers
+        #[arg(long, default_value = DEFAULT_HISTORY_PATH)]
+        history: String,
+    },
+    /// Generate a new day's solution from a template and register it
+    New {
+        /// Specify which day to create
+        #[arg(long, short, value_parser = parse_day)]
+        day: usize,
+        /// Specify which template to start from
+        #[arg(long, short, value_enum)]
+        kind: SolutionKind,
+        /// Specify the puzzle's title
+        #[arg(long, short)]
+        title: Option<String>,
+        /// Specify the directory holding the solution files
+        #[arg(long, default_value = DEFAULT_SOLUTIONS_DIR)]
+        solutions_dir: String,
+    },
 }
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
     #[arg(long, short, default_value = "example")]
     /// Example: run an example. Single: run a single day's solution. All: Run all solutions sequentially.
+    /// Verify: run all solutions and check them against the answers file.
+    /// Bench: repeatedly run the selected days and report timing statistics.
+    /// All, verify and bench run every day unless --day, --days or --latest is given.
     mode: RunMode,
-    /// Specify which day's solution to run - only used when --mode is single.
-    #[arg(long, short, required_if_eq("mode", "single"))]
+    /// Specify which day's solution to run.
+    #[arg(long, short, value_parser = parse_day)]
     day: Option<usize>,
-    /// Specify the filepath to the day's input - only used when --mode is single
+    /// Specify a set of days to run, e.g. 3,7,10-14
+    #[arg(long, conflicts_with_all = ["day", "latest"])]
+    days: Option<DaySet>,
+    /// Run the most recently solved day
+    #[arg(long, conflicts_with = "day")]
+    latest: bool,
+    /// Only solve this part of each day - only used when --mode is single, all, verify or bench
+    #[arg(long, short, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Override one of the day's puzzle params, e.g. --param target=10. Can be given more than once - only used when a single day is selected
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_pair)]
+    params: Vec<(String, String)>,
+    /// Specify the filepath to the day's input, or - to read it from stdin - only used when a single day is selected
     #[arg(long, short)]
     input: Option<String>,
+    /// Run the day against each of its examples instead of its input, checking the answers - only used when --mode is single
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+    /// Specify the directory holding each year's examples - only used with --example and by new
+    #[arg(long, global = true, default_value = DEFAULT_EXAMPLES_DIR)]
+    examples_dir: String,
+    /// Specify which AoC event to run solutions for. Defaults to the latest year with solutions
+    #[arg(long, short, global = true, default_value_t = default_year())]
+    year: usize,
+    /// Specify where to find each day's input. {year} is replaced with the year, and {day} and {day:02} with the day number
+    #[arg(long, global = true, default_value = DEFAULT_INPUT_PATTERN)]
+    input_pattern: String,
+    /// Download any missing inputs before running, instead of asking
+    #[arg(long)]
+    fetch_missing: bool,
+    /// Specify the filepath to the config file holding the AoC session token and base URL
+    #[arg(long, global = true, default_value = DEFAULT_CONFIG_PATH)]
+    config: String,
+    /// Specify the AoC server to download inputs from, overriding the environment and config file
+    #[arg(long, global = true)]
+    base_url: Option<String>,
+    /// Specify how many days to solve at once - only used when --mode is all
+    #[arg(long, short, default_value_t = 1)]
+    jobs: usize,
+    /// Give up on a day after this many seconds and report it as timed out - only used when --mode is all or verify
+    #[arg(long, value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+    /// Specify where to find each year's known answers. {year} is replaced with the year - only used when --mode is verify
+    #[arg(long, default_value = DEFAULT_ANSWERS_PATTERN)]
+    answers: String,
+    /// Specify how solve reports are printed - only used when --mode is single, all or bench
+    #[arg(long, short, value_enum, default_value_t)]
+    format: OutputFormat,
+    /// Specify how many timed runs to make per day - only used when --mode is bench
+    #[arg(long, default_value_t = 100)]
+    iterations: usize,
+    /// Specify how many untimed runs to make per day before timing - only used when --mode is bench
+    #[arg(long, default_value_t = 10)]
+    warmup: usize,
+    /// Save the benchmark results to this file as a baseline - only used when --mode is bench
+    #[arg(long)]
+    save_baseline: Option<String>,
+    /// Compare the benchmark results against a saved baseline - only used when --mode is bench
+    #[arg(long)]
+    compare: Option<String>,
+    /// Percentage slowdown in median total time that counts as a regression - only used with --compare
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
 }
 
-fn main() {
+fn main() -> ExitCode {
     let args = Args::parse();
+    let config = config_for(&args);
 
-    match args.mode {
-        RunMode::Example => run_example(),
-        RunMode::All => {
-            let result = run_all();
-            if let Err(err) = result {
-                println!("Failed to run solutions. Reason: {}", err)
+    if let Some(Command::Fetch { day, days, force }) = &args.command {
+        let days = match (day, days) {
+            (Some(day), _) => vec![*day],
+            (_, Some(DaySet(days))) => days.clone(),
+            _ => unreachable!("clap requires --day or --days"),
+        };
+        return match run_fetch(&days, &config, *force) {
+            Result::Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                println!("Failed to fetch inputs. Reason: {}", err);
+                ExitCode::FAILURE
             }
-        }
-        RunMode::Single => {
-            let result = run_single(args.day.unwrap(), args.input);
+        };
+    }
 
-            if let Err(err) = result {
-                println!(
-                    "Failed to run solution for Day {}. Reason: {}",
-                    args.day.unwrap(),
-                    err
-                )
+    if let Some(Command::Submit { day, part, history }) = &args.command {
+        return match run_submit(*day, *part, Path::new(history), &config, confirm) {
+            Result::Ok(true) => ExitCode::SUCCESS,
+            Result::Ok(false) => ExitCode::FAILURE,
+            Err(err) => {
+                println!("Failed to submit answer. Reason: {}", err);
+                ExitCode::FAILURE
             }
-        }
+        };
     }
-}
 
-/// Load a puzzle input from a .txt file
-fn load_from_file(file_path: &Path) -> Result<String> {
-    if !file_path.is_file()
-        || file_path.extension().is_none()
-        || file_path.extension().unwrap() != "txt"
+    if let Some(Command::New {
+        day,
+        kind,
+        title,
+        solutions_dir,
+    }) = &args.command
     {
-        return Err(anyhow!(
-            "input path '{:?}' is not valid. Please provide a path to a valid text file.",
-            file_path.to_str()
-        ));
+        let title = title.clone().unwrap_or_else(|| format!("Day {}", day));
+        return match scaffold(
+            Path::new(solutions_dir),
+            Path::new(&args.examples_dir),
+            args.year,
+            *day,
+            &title,
+            *kind,
+        ) {
+            Result::Ok(()) => {
+                println!(
+                    "Created {}/y{}/day{:02}.rs and added it to the solutions module. Its example is in {}",
+                    solutions_dir,
+                    args.year,
+                    day,
+                    example_dir(Path::new(&args.examples_dir), args.year, *day).display()
+                );
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                println!("Failed to create day {}. Reason: {}", day, err);
+                ExitCode::FAILURE
+            }
+        };
     }
 
-    match fs::read_to_string(file_path) {
-        Err(error) => Err(anyhow!(
-            "Failed to read file. Reason: {}",
-            error.to_string()
-        )),
-        Result::Ok(text) => Ok(text),
+    if let RunMode::Example = args.mode {
+        run_example();
+        return ExitCode::SUCCESS;
     }
-}
 
-/// run a single specified day's solution
-fn run_single(day: usize, input_path: Option<String>) -> Result<f32> {
-    if day < 1 || day > SOLUTIONS.len() {
-        return Err(anyhow!("Day '{}' is invalid or not yet solved", day));
+    let days = match selected_days(&args) {
+        Result::Ok(days) => days,
+        Err(err) => {
+            println!("Failed to select days. Reason: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // only ask before downloading when running a single day, otherwise --fetch-missing is needed
+    if !args.example {
+        match (args.fetch_missing, &args.mode) {
+            (true, _) => fetch_missing_inputs(&days, &config, None),
+            (false, RunMode::Single) => fetch_missing_inputs(&days, &config, Some(&confirm)),
+            _ => (),
+        }
     }
 
-    let unwrapped_path = input_path.unwrap_or(format!("./inputs/input_{:02}.txt", day));
-    let file_path = Path::new(&unwrapped_path);
+    match args.mode {
+        RunMode::Example => unreachable!(),
+        RunMode::All => {
+            if !run_all(&days, &config) {
+                return ExitCode::FAILURE;
+            }
+        }
+        RunMode::Single if args.example => match run_examples(days[0], &config) {
+            Result::Ok(true) => (),
+            Result::Ok(false) => return ExitCode::FAILURE,
+            Err(err) => {
+                println!(
+                    "Failed to run examples for Day {}. Reason: {}",
+                    days[0], err
+                );
+                return ExitCode::FAILURE;
+            }
+        },
+        RunMode::Single => match run_day(days[0], &config) {
+            Result::Ok(()) => (),
+            Err(err) => {
+                println!(
+                    "Failed to run solution for Day {}. Reason: {}",
+                    days[0], err
+                );
+                return ExitCode::FAILURE;
+            }
+        },
+        RunMode::Bench => match run_bench(&days, &config) {
+            Result::Ok(true) => (),
+            Result::Ok(false) => return ExitCode::FAILURE,
+            Err(err) => {
+                println!("Failed to benchmark solutions. Reason: {}", err);
+                return ExitCode::FAILURE;
+            }
+        },
+        RunMode::Verify => match run_verify(&days, &config) {
+            Result::Ok(true) => (),
+            Result::Ok(false) => return ExitCode::FAILURE,
+            Err(err) => {
+                println!("Failed to verify solutions. Reason: {}", err);
+                return ExitCode::FAILURE;
+            }
+        },
+    }
 
-    let input = load_from_file(file_path)?;
+    ExitCode::SUCCESS
+}
 
-    SOLUTIONS[day - 1](&input)
+/// the latest year with any solutions, or the first AoC event if there are none yet
+fn default_year() -> usize {
+    registry::latest_year().unwrap_or(2015)
 }
 
-/// run all solutions
-fn run_all() -> Result<()> {
-    let mut time_total = 0.0;
+/// get the days selected by --day, --days or --latest, defaulting to every solved day
+fn selected_days(args: &Args) -> Result<Vec<usize>> {
+    let days = match (args.day, &args.days, args.latest) {
+        (Some(day), _, _) => vec![day],
+        (_, Some(DaySet(days)), _) => days.clone(),
+        (_, _, true) => vec![registry::latest(args.year)
+            .ok_or_else(|| anyhow!("No days of {} are solved yet", args.year))?],
+        _ => registry::solved_days(args.year),
+    };
 
-    for i in 0..SOLUTIONS.len() {
-        println!("\nDay {:02}:\n", i + 1);
-        time_total += run_single(i + 1, None)?;
+    if let RunMode::Single = args.mode {
+        if args.day.is_none() && !args.latest {
+            return Err(anyhow!("Please specify a day with --day or --latest"));
+        }
+    }
+    if args.input.is_some() && days.len() > 1 {
+        return Err(anyhow!(
+            "--input can only be used with a single day. Use --input-pattern instead"
+        ));
+    }
+    if !args.params.is_empty() && days.len() > 1 {
+        return Err(anyhow!("--param can only be used with a single day"));
     }
 
-    println!("\nSolved all problems in: {}ms", time_total);
-
-    Ok(())
+    Ok(days)
 }
 
-fn run_example() {
-    println!("Here's an example of a linear solution:");
-    print!(
-        r"
-input: [1,2,3,4,5]
-part 1: sum up these numbers
-part 2: multiply the result of part 1 by the number of numbers in the input
-"
-    );
-
-    let solved_in_1 = solve_linear::<ExampleSolutionLinear, _, _, _>("[1,2,3,4,5]").unwrap();
-
-    println!("\nHere's an example of a simultaneous solution:");
-    print!(
-        r"
-input: [6,5,4,2,3,5,8]
-part 1: get the first number that's higher than the previous
-part 2: get the number after the first number that's higher than the previous
-"
-    );
-
-    let solved_in_2 =
-        solve_simultaneous::<ExampleSolutionSimultaneous, _, _, _>("[6,5,4,2,3,5,8]").unwrap();
-
-    println!("Example time:\t\t{}ms", solved_in_1 + solved_in_2);
+/// gather everything that decides how days are run from the args
+fn config_for(args: &Args) -> RunConfig {
+    RunConfig {
+        year: args.year,
+        input_pattern: args.input_pattern.clone(),
+        input: args.input.clone(),
+        parts: args
+            .part
+            .map(|part| Parts::from_part(Some(part)).expect("clap only accepts part 1 or 2")),
+        params: Params(args.params.iter().cloned().collect()),
+        examples_dir: PathBuf::from(&args.examples_dir),
+        answers_pattern: args.answers.clone(),
+        config: PathBuf::from(&args.config),
+        base_url: args.base_url.clone(),
+        jobs: args.jobs,
+        timeout: args.timeout,
+        format: args.format,
+        bench: BenchConfig {
+            iterations: args.iterations,
+            warmup: args.warmup,
+            save_baseline: args.save_baseline.as_ref().map(PathBuf::from),
+            compare: args.compare.as_ref().map(PathBuf::from),
+            threshold: args.threshold,
+        },
+    }
 }
@@ -0,0 +1,538 @@
+//! The ways of running solutions that the command line offers: solving, verifying, benchmarking,
+//! checking examples, and fetching and submitting to the AoC server. Each prints its results as it goes
+//!
+//! ```no_run
+//! use aoc2022::modes::{run_verify, RunConfig};
+//!
+//! let config = RunConfig {
+//!     year: 2022,
+//!     ..Default::default()
+//! };
+//! let all_correct = run_verify(&[1, 2, 3], &config).unwrap();
+//! ```
+
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    bench::{bench, compare, load_baseline, save_baseline, BenchReport},
+    client::{fetch_to_cache, AocClient, DEFAULT_CONFIG_PATH},
+    examples::{load_examples, solve_example, Example, DEFAULT_EXAMPLES_DIR},
+    output::{as_ms, render, render_bench, render_comparison, render_report, OutputFormat},
+    parallel::map_parallel,
+    registry,
+    runner::{load_input, run_single, run_with_timeout, solution_for, DayOutcome, STDIN_PATH},
+    selection::{input_path, year_path, DEFAULT_ANSWERS_PATTERN, DEFAULT_INPUT_PATTERN},
+    solutions::templates::{
+        linear_template::ExampleSolutionLinear, simultaneous_template::ExampleSolutionSimultaneous,
+    },
+    submit::{submit, Check, History, Outcome},
+    utils::{
+        answers::{Answers, Verdict},
+        params::Params,
+        solver_types::{solve_linear, solve_simultaneous, Parts, SolveReport},
+    },
+};
+
+/// Everything that decides how days are run. The defaults match the command line's
+#[derive(Debug, Clone)]
+pub struct RunConfig {
+    pub year: usize,
+    /// where to find each day's input. See `selection::input_path`
+    pub input_pattern: String,
+    /// an input to use instead of the pattern, or `-` for stdin. Only makes sense for a single day
+    pub input: Option<String>,
+    /// which parts to solve. `None` solves both, except for examples, which solve the parts they have answers for
+    pub parts: Option<Parts>,
+    /// overrides for the day's puzzle params
+    pub params: Params,
+    pub examples_dir: PathBuf,
+    /// where to find each year's known answers. See `selection::year_path`
+    pub answers_pattern: String,
+    /// the config file holding the AoC session token and base URL
+    pub config: PathBuf,
+    /// an AoC server to use instead of the one in the environment or config file
+    pub base_url: Option<String>,
+    /// how many days to solve at once
+    pub jobs: usize,
+    /// give up on a day after this long
+    pub timeout: Option<Duration>,
+    pub format: OutputFormat,
+    pub bench: BenchConfig,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            year: registry::latest_year().unwrap_or(2015),
+            input_pattern: DEFAULT_INPUT_PATTERN.to_string(),
+            input: None,
+            parts: None,
+            params: Params::default(),
+            examples_dir: PathBuf::from(DEFAULT_EXAMPLES_DIR),
+            answers_pattern: DEFAULT_ANSWERS_PATTERN.to_string(),
+            config: PathBuf::from(DEFAULT_CONFIG_PATH),
+            base_url: None,
+            jobs: 1,
+            timeout: None,
+            format: OutputFormat::default(),
+            bench: BenchConfig::default(),
+        }
+    }
+}
+
+impl RunConfig {
+    /// the parts to solve, defaulting to both
+    pub fn parts(&self) -> Parts {
+        self.parts.unwrap_or_default()
+    }
+
+    /// the path to a day's input, preferring `input` over `input_pattern`
+    pub fn input_path(&self, day: usize) -> String {
+        self.input
+            .clone()
+            .unwrap_or_else(|| input_path(&self.input_pattern, self.year, day))
+    }
+
+    /// a client for the AoC server, using the config file, environment and `base_url`
+    pub fn client(&self) -> Result<AocClient> {
+        AocClient::from_config(&self.config, self.base_url.as_deref())
+    }
+
+    /// run a day's solution, catching failures and applying the timeout
+    fn outcome(&self, day: usize) -> DayOutcome {
+        run_with_timeout(
+            self.year,
+            day,
+            &self.input_path(day),
+            self.parts(),
+            &self.params,
+            self.timeout,
+        )
+    }
+}
+
+/// How to benchmark days, and what to do with the results
+#[derive(Debug, Clone)]
+pub struct BenchConfig {
+    /// how many timed runs to make per day
+    pub iterations: usize,
+    /// how many untimed runs to make per day before timing
+    pub warmup: usize,
+    /// save the results to this file as a baseline
+    pub save_baseline: Option<PathBuf>,
+    /// compare the results against a saved baseline
+    pub compare: Option<PathBuf>,
+    /// percentage slowdown in median total time that counts as a regression
+    pub threshold: f64,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            iterations: 100,
+            warmup: 10,
+            save_baseline: None,
+            compare: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+/// download the inputs for a set of days to their `input_pattern` paths
+pub fn run_fetch(days: &[usize], config: &RunConfig, force: bool) -> Result<()> {
+    let client = config.client()?;
+
+    for &day in days {
+        let path = input_path(&config.input_pattern, config.year, day);
+
+        match fetch_to_cache(&client, config.year, day, Path::new(&path), force)? {
+            true => println!("Day {:02}: downloaded to {}", day, path),
+            false => println!("Day {:02}: already downloaded to {}", day, path),
+        }
+    }
+
+    Ok(())
+}
+
+/// solve a day and submit one part's answer, recording the response in the history file.
+/// `confirm` is asked whether to go ahead when the history has doubts about the answer.
+/// Returns true if the answer was correct
+pub fn run_submit(
+    day: usize,
+    part: u8,
+    history_path: &Path,
+    config: &RunConfig,
+    confirm: impl Fn(&str) -> bool,
+) -> Result<bool> {
+    let report = run_single(
+        config.year,
+        day,
+        &input_path(&config.input_pattern, config.year, day),
+        Parts::from_part(Some(part))?,
+        &Params::default(),
+    )?;
+    let answer = match part {
+        1 => report.part_1,
+        _ => report.part_2,
+    }
+    .ok_or_else(|| anyhow!("Day {} didn't produce an answer for part {}", day, part))?;
+    let part = part as usize;
+
+    let mut history = History::load(history_path)?;
+
+    match history.check(config.year, day, part, &answer) {
+        Check::Ok => (),
+        Check::Refuse(reason) => return Err(anyhow!("Not submitting. {}", reason)),
+        Check::Warn(reason) => {
+            if !confirm(&format!("{}. Submit anyway?", reason)) {
+                return Ok(false);
+            }
+        }
+    }
+
+    let client = config.client()?;
+    println!("Submitting '{}' for day {} part {}", answer, day, part);
+
+    let submission = submit(&client, &mut history, config.year, day, part, &answer)?;
+    history.save(history_path)?;
+
+    println!("{}\n{}", submission.outcome, submission.message);
+
+    Ok(submission.outcome == Outcome::Correct)
+}
+
+/// ask a yes/no question on the terminal, defaulting to no
+pub fn confirm(question: &str) -> bool {
+    print!("{} [y/N] ", question);
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    match io::stdin().read_line(&mut answer) {
+        Ok(_) => matches!(answer.trim(), "y" | "Y" | "yes"),
+        Err(_) => false,
+    }
+}
+
+/// download inputs that don't exist yet. If `confirm` is given, it's asked before each download
+pub fn fetch_missing_inputs(
+    days: &[usize],
+    config: &RunConfig,
+    confirm: Option<&dyn Fn(&str) -> bool>,
+) {
+    let missing = days
+        .iter()
+        .map(|&day| (day, config.input_path(day)))
+        .filter(|(_, path)| path != STDIN_PATH && !Path::new(path).exists())
+        .collect::<Vec<_>>();
+
+    if missing.is_empty() {
+        return;
+    }
+
+    let client = match config.client() {
+        Ok(client) => client,
+        Err(err) => {
+            println!("Can't fetch missing inputs. Reason: {}", err);
+            return;
+        }
+    };
+
+    for (day, path) in missing {
+        let question = format!(
+            "Input for day {} not found at {}. Download it from {}?",
+            day,
+            path,
+            client.base_url()
+        );
+        if confirm.is_some_and(|confirm| !confirm(&question)) {
+            continue;
+        }
+
+        if let Err(err) = fetch_to_cache(&client, config.year, day, Path::new(&path), false) {
+            println!("Failed to fetch input for day {}. Reason: {}", day, err);
+        }
+    }
+}
+
+/// run the solutions for a set of days, solving up to `jobs` days at once.
+/// Days that fail or panic don't stop the others, and are listed in a summary at the end.
+/// Returns true if every day was solved
+pub fn run_all(days: &[usize], config: &RunConfig) -> bool {
+    let start = Instant::now();
+
+    let outcomes = map_parallel(days, config.jobs, |&day| config.outcome(day));
+
+    let wall_time = start.elapsed();
+
+    let reports = outcomes
+        .iter()
+        .filter_map(|outcome| match outcome {
+            DayOutcome::Solved(report) => Some(report.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    print!("{}", render(&reports, Some(wall_time), config.format));
+
+    let unsolved = days
+        .iter()
+        .zip(outcomes.iter())
+        .filter(|(_, outcome)| !matches!(outcome, DayOutcome::Solved(_)))
+        .map(|(day, outcome)| format!("Day {:02}:\t{}", day, outcome))
+        .collect::<Vec<_>>();
+
+    let summary = format!(
+        "\nSolved {} of {} days{}{}",
+        reports.len(),
+        days.len(),
+        if unsolved.is_empty() {
+            ""
+        } else {
+            ". Unsolved:\n"
+        },
+        unsolved.join("\n")
+    );
+
+    // keep machine-readable output parseable
+    match config.format {
+        OutputFormat::Text | OutputFormat::Markdown => println!("{}", summary),
+        OutputFormat::Json | OutputFormat::Csv => eprintln!("{}", summary),
+    }
+
+    unsolved.is_empty()
+}
+
+/// solve a single day, printing its report the same way as `run_all`
+pub fn run_day(day: usize, config: &RunConfig) -> Result<()> {
+    let start = Instant::now();
+    let report = run_single(
+        config.year,
+        day,
+        &config.input_path(day),
+        config.parts(),
+        &config.params,
+    )?;
+
+    print!(
+        "{}",
+        render(&[report], Some(start.elapsed()), config.format)
+    );
+    Ok(())
+}
+
+/// benchmark the solutions for a set of days.
+/// Returns false if any day regressed compared to the baseline
+pub fn run_bench(days: &[usize], config: &RunConfig) -> Result<bool> {
+    let settings = &config.bench;
+    // load the baseline first, so a bad one doesn't waste a benchmarking run
+    let baseline = settings
+        .compare
+        .as_ref()
+        .map(|path| load_baseline(path, config.year))
+        .transpose()?;
+
+    let reports = days
+        .iter()
+        .map(|&day| {
+            let solution = solution_for(config.year, day)?;
+            let input = load_input(&config.input_path(day))?;
+            bench(
+                day,
+                solution.solution,
+                config.parts(),
+                &config.params,
+                &input,
+                settings.warmup,
+                settings.iterations,
+            )
+        })
+        .collect::<Result<Vec<BenchReport>>>()?;
+
+    // keep machine-readable output to a single document when comparing
+    if baseline.is_none() || matches!(config.format, OutputFormat::Text | OutputFormat::Markdown) {
+        print!("{}", render_bench(&reports, config.format));
+    }
+
+    if let Some(path) = &settings.save_baseline {
+        save_baseline(path, config.year, &reports)?;
+    }
+
+    match baseline {
+        None => Ok(true),
+        Some(baseline) => {
+            let comparisons = compare(&baseline, &reports, settings.threshold);
+
+            print!("{}", render_comparison(&comparisons, config.format));
+
+            Ok(!comparisons.iter().any(|c| c.regression))
+        }
+    }
+}
+
+/// run a day against each of its examples, with any `parts` and `params` overriding the example's own.
+/// Returns true if no answers were wrong
+pub fn run_examples(day: usize, config: &RunConfig) -> Result<bool> {
+    let registration = solution_for(config.year, day)?;
+    let examples = load_examples(&config.examples_dir, config.year, day)?;
+
+    if examples.is_empty() {
+        return Err(anyhow!(
+            "No examples found for day {} of {} in {}",
+            day,
+            config.year,
+            config.examples_dir.display()
+        ));
+    }
+
+    let mut failed = 0;
+
+    for example in examples {
+        let mut params = example.params.clone();
+        params.0.extend(config.params.0.clone());
+        let example = Example { params, ..example };
+
+        let parts = config.parts.unwrap_or_else(|| example.parts());
+        let (report, (v1, v2)) = solve_example(registration.solution, &example, parts)?;
+
+        println!("Example '{}':", example.name);
+        print!("{}", render_report(&SolveReport { day, ..report }));
+
+        for (part, verdict) in [(1, v1), (2, v2)] {
+            if let Verdict::Fail { .. } = verdict {
+                failed += 1;
+            }
+            if verdict != Verdict::Skipped {
+                println!("Part {}:\t\t\t{}", part, verdict);
+            }
+        }
+        println!();
+    }
+
+    Ok(failed == 0)
+}
+
+/// run the solutions for a set of days and check their answers against the answers file.
+/// Returns true if no answers were wrong
+pub fn run_verify(days: &[usize], config: &RunConfig) -> Result<bool> {
+    let answers = Answers::load(Path::new(&year_path(&config.answers_pattern, config.year)))?;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for &day in days {
+        let report = match config.outcome(day) {
+            DayOutcome::Solved(report) => report,
+            outcome => {
+                println!("Day {:02}:\t\tFAIL ({})", day, outcome);
+                failed += config.parts().count();
+                continue;
+            }
+        };
+
+        let (v1, v2) = answers.verify(&report);
+
+        for (part, verdict) in [(1, v1), (2, v2)] {
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Missing => missing += 1,
+                Verdict::Skipped => continue,
+            }
+            println!("Day {:02} Part {}:\t{}", day, part, verdict);
+        }
+    }
+
+    println!(
+        "\nPassed: {}\tFailed: {}\tMissing: {}",
+        passed, failed, missing
+    );
+
+    Ok(failed == 0)
+}
+
+/// solve the templates' examples, to show how each kind of solution works
+pub fn run_example() {
+    println!("Here's an example of a linear solution:");
+    print!(
+        r"
+input: [1,2,3,4,5]
+part 1: sum up these numbers
+part 2: multiply the result of part 1 by the number of numbers in the input
+"
+    );
+
+    let solved_1 = solve_linear::<ExampleSolutionLinear, _, _, _>("[1,2,3,4,5]").unwrap();
+    print!("{}", render_report(&solved_1));
+
+    println!("\nHere's an example of a simultaneous solution:");
+    print!(
+        r"
+input: [6,5,4,2,3,5,8]
+part 1: get the first number that's higher than the previous
+part 2: get the number after the first number that's higher than the previous
+"
+    );
+
+    let solved_2 =
+        solve_simultaneous::<ExampleSolutionSimultaneous, _, _, _>("[6,5,4,2,3,5,8]").unwrap();
+    print!("{}", render_report(&solved_2));
+
+    println!(
+        "Example time:\t\t{}ms",
+        as_ms(solved_1.total_time() + solved_2.total_time())
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path};
+
+    use super::{run_examples, run_verify, RunConfig};
+    use crate::utils::{params::Params, solver_types::Parts};
+
+    fn config() -> RunConfig {
+        RunConfig {
+            year: 2022,
+            examples_dir: Path::new(env!("CARGO_MANIFEST_DIR")).join("puzzle_examples"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_run_examples() {
+        assert!(run_examples(9, &config()).unwrap());
+
+        // overriding the params makes the larger example's answer wrong
+        let config = RunConfig {
+            parts: Some(Parts::Two),
+            params: Params::parse(&["knots=3"]).unwrap(),
+            ..config()
+        };
+        assert!(!run_examples(9, &config).unwrap());
+    }
+
+    #[test]
+    fn test_run_verify() {
+        let dir = env::temp_dir().join("aoc2022_modes");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("input_01.txt"), "1000\n\n2000\n3000\n\n500").unwrap();
+        fs::write(dir.join("2022.toml"), "[day01]\npart1 = 5000\npart2 = 1\n").unwrap();
+
+        let config = RunConfig {
+            input_pattern: dir.join("input_{day:02}.txt").display().to_string(),
+            answers_pattern: dir.join("{year}.toml").display().to_string(),
+            ..config()
+        };
+        assert!(!run_verify(&[1], &config).unwrap());
+
+        let config = RunConfig {
+            parts: Some(Parts::One),
+            ..config
+        };
+        assert!(run_verify(&[1], &config).unwrap());
+    }
+}
@@ -0,0 +1,465 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{
+    bench::{BenchReport, Comparison, Stats},
+    utils::solver_types::SolveReport,
+};
+
+/// The formats we can render solve reports in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+/// convert a duration to fractional milliseconds for display
+pub fn as_ms(duration: Duration) -> f32 {
+    duration.as_secs_f32() * 1000.0
+}
+
+/// A single day's answers and timings, flattened for machine-readable output
+#[derive(Serialize)]
+struct DayRow<'a> {
+    day: usize,
+    part_1: Option<&'a str>,
+    part_2: Option<&'a str>,
+    parse_ms: f32,
+    part_1_ms: f32,
+    part_2_ms: f32,
+    total_ms: f32,
+    solved_together: bool,
+}
+
+impl<'a> From<&'a SolveReport> for DayRow<'a> {
+    fn from(report: &'a SolveReport) -> Self {
+        Self {
+            day: report.day,
+            part_1: report.part_1.as_deref(),
+            part_2: report.part_2.as_deref(),
+            parse_ms: as_ms(report.parse_time),
+            part_1_ms: as_ms(report.part_1_time),
+            part_2_ms: as_ms(report.part_2_time),
+            total_ms: as_ms(report.total_time()),
+            solved_together: report.solved_together,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct RunDocument<'a> {
+    days: Vec<DayRow<'a>>,
+    total_ms: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wall_ms: Option<f32>,
+}
+
+/// render a set of solve reports in the given format.
+/// `wall_time` is the real time taken to solve every report, which is less than their total when run in parallel
+pub fn render(
+    reports: &[SolveReport],
+    wall_time: Option<Duration>,
+    format: OutputFormat,
+) -> String {
+    match format {
+        OutputFormat::Text => render_text(reports, wall_time),
+        OutputFormat::Json => render_json(reports, wall_time),
+        OutputFormat::Csv => render_csv(reports),
+        OutputFormat::Markdown => render_markdown(reports, wall_time),
+    }
+}
+
+fn total_time(reports: &[SolveReport]) -> Duration {
+    reports.iter().map(SolveReport::total_time).sum()
+}
+
+/// the answers and timings of a solved day, as printed to the terminal
+pub fn render_report(report: &SolveReport) -> String {
+    let mut res = String::new();
+
+    res.push_str(&format!(
+        "Parsed input in:\t{}ms\n",
+        as_ms(report.parse_time)
+    ));
+    if let Some(part_1) = &report.part_1 {
+        res.push_str(&format!("Part 1 Solution: \t{}\n", part_1));
+        if !report.solved_together {
+            res.push_str(&format!(
+                "Part 1 solved in:\t{}ms\n",
+                as_ms(report.part_1_time)
+            ));
+        }
+    }
+    if let Some(part_2) = &report.part_2 {
+        res.push_str(&format!("Part 2 Solution: \t{}\n", part_2));
+        if !report.solved_together {
+            res.push_str(&format!(
+                "Part 2 solved in:\t{}ms\n",
+                as_ms(report.part_2_time)
+            ));
+        }
+    }
+    if report.solved_together {
+        res.push_str(&format!("Solved in:\t\t{}ms\n", as_ms(report.part_1_time)));
+    }
+    res.push_str(&format!(
+        "Overall time:\t\t{}ms\n",
+        as_ms(report.total_time())
+    ));
+
+    res
+}
+
+fn render_text(reports: &[SolveReport], wall_time: Option<Duration>) -> String {
+    let mut res = String::new();
+
+    for report in reports {
+        res.push_str(&format!("\nDay {:02}:\n\n", report.day));
+        res.push_str(&render_report(report));
+    }
+
+    res.push_str(&format!(
+        "\nSolved all problems in: {}ms\n",
+        as_ms(total_time(reports))
+    ));
+
+    if let Some(wall_time) = wall_time {
+        res.push_str(&format!("Wall-clock time:\t{}ms\n", as_ms(wall_time)));
+    }
+
+    res
+}
+
+fn render_json(reports: &[SolveReport], wall_time: Option<Duration>) -> String {
+    let document = RunDocument {
+        days: reports.iter().map(DayRow::from).collect(),
+        total_ms: as_ms(total_time(reports)),
+        wall_ms: wall_time.map(as_ms),
+    };
+
+    serde_json::to_string_pretty(&document).expect("reports are always serializable")
+}
+
+/// quote a csv field if it contains anything that would break the row
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn render_csv(reports: &[SolveReport]) -> String {
+    let mut res =
+        String::from("day,part_1,part_2,parse_ms,part_1_ms,part_2_ms,total_ms,solved_together\n");
+
+    for row in reports.iter().map(DayRow::from) {
+        res.push_str(&format!(
+            "{},{},{},{},{},{},{},{}\n",
+            row.day,
+            csv_field(row.part_1.unwrap_or_default()),
+            csv_field(row.part_2.unwrap_or_default()),
+            row.parse_ms,
+            row.part_1_ms,
+            row.part_2_ms,
+            row.total_ms,
+            row.solved_together
+        ));
+    }
+
+    res
+}
+
+/// escape a value so it fits in a single markdown table cell
+fn markdown_cell(cell: &str) -> String {
+    cell.trim_matches('\n')
+        .replace('|', "\\|")
+        .replace('\n', "<br>")
+}
+
+fn render_markdown(reports: &[SolveReport], wall_time: Option<Duration>) -> String {
+    let mut res = String::from(
+        "| Day | Part 1 | Part 2 | Parse (ms) | Part 1 (ms) | Part 2 (ms) | Total (ms) |\n\
+         |----:|--------|--------|-----------:|------------:|------------:|-----------:|\n",
+    );
+
+    for row in reports.iter().map(DayRow::from) {
+        res.push_str(&format!(
+            "| {:02} | {} | {} | {:.3} | {:.3} | {:.3} | {:.3} |\n",
+            row.day,
+            markdown_cell(row.part_1.unwrap_or("-")),
+            markdown_cell(row.part_2.unwrap_or("-")),
+            row.parse_ms,
+            row.part_1_ms,
+            row.part_2_ms,
+            row.total_ms
+        ));
+    }
+
+    res.push_str(&format!(
+        "| **Total** | | | | | | {:.3} |\n",
+        as_ms(total_time(reports))
+    ));
+
+    if let Some(wall_time) = wall_time {
+        res.push_str(&format!(
+            "| **Wall-clock** | | | | | | {:.3} |\n",
+            as_ms(wall_time)
+        ));
+    }
+
+    res
+}
+
+/// render a set of benchmark reports in the given format
+pub fn render_bench(reports: &[BenchReport], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(reports).expect("reports are always serializable")
+        }
+        OutputFormat::Csv => {
+            let mut res =
+                String::from("day,phase,iterations,min_ms,median_ms,mean_ms,p95_ms,stddev_ms\n");
+            for (report, phase, stats) in bench_rows(reports) {
+                res.push_str(&format!(
+                    "{},{},{},{},{},{},{},{}\n",
+                    report.day,
+                    phase,
+                    report.iterations,
+                    stats.min,
+                    stats.median,
+                    stats.mean,
+                    stats.p95,
+                    stats.stddev
+                ));
+            }
+            res
+        }
+        OutputFormat::Markdown => {
+            let mut res = String::from(
+                "| Day | Phase | Min (ms) | Median (ms) | Mean (ms) | p95 (ms) | Std dev (ms) |\n\
+                 |----:|-------|---------:|------------:|----------:|---------:|-------------:|\n",
+            );
+            for (report, phase, stats) in bench_rows(reports) {
+                res.push_str(&format!(
+                    "| {:02} | {} | {:.4} | {:.4} | {:.4} | {:.4} | {:.4} |\n",
+                    report.day, phase, stats.min, stats.median, stats.mean, stats.p95, stats.stddev
+                ));
+            }
+            res
+        }
+        OutputFormat::Text => {
+            let mut res = String::new();
+            for report in reports {
+                res.push_str(&format!(
+                    "\nDay {:02} ({} iterations):\n{:<8}{:>12}{:>12}{:>12}{:>12}{:>12}\n",
+                    report.day, report.iterations, "", "min", "median", "mean", "p95", "stddev"
+                ));
+                for (_, phase, stats) in bench_rows(std::slice::from_ref(report)) {
+                    res.push_str(&format!(
+                        "{:<8}{:>10.4}ms{:>10.4}ms{:>10.4}ms{:>10.4}ms{:>10.4}ms\n",
+                        phase, stats.min, stats.median, stats.mean, stats.p95, stats.stddev
+                    ));
+                }
+            }
+            res
+        }
+    }
+}
+
+/// render the differences between a baseline and a new benchmark run in the given format
+pub fn render_comparison(comparisons: &[Comparison], format: OutputFormat) -> String {
+    let status = |c: &Comparison| if c.regression { "REGRESSION" } else { "ok" };
+
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(comparisons).expect("comparisons are always serializable")
+        }
+        OutputFormat::Csv => {
+            let mut res =
+                String::from("day,baseline_ms,current_ms,delta_ms,change_percent,regression\n");
+            for c in comparisons {
+                res.push_str(&format!(
+                    "{},{},{},{},{},{}\n",
+                    c.day, c.baseline_ms, c.current_ms, c.delta_ms, c.change_percent, c.regression
+                ));
+            }
+            res
+        }
+        OutputFormat::Markdown => {
+            let mut res = String::from(
+                "| Day | Baseline (ms) | Current (ms) | Delta (ms) | Change | Status |\n\
+                 |----:|--------------:|-------------:|-----------:|-------:|--------|\n",
+            );
+            for c in comparisons {
+                res.push_str(&format!(
+                    "| {:02} | {:.4} | {:.4} | {:+.4} | {:+.1}% | {} |\n",
+                    c.day,
+                    c.baseline_ms,
+                    c.current_ms,
+                    c.delta_ms,
+                    c.change_percent,
+                    status(c)
+                ));
+            }
+            res
+        }
+        OutputFormat::Text => {
+            let mut res = format!(
+                "\nMedian total time compared to baseline:\n{:<6}{:>14}{:>14}{:>14}{:>10}\n",
+                "", "baseline", "current", "delta", "change"
+            );
+            for c in comparisons {
+                res.push_str(&format!(
+                    "Day {:02}{:>12.4}ms{:>12.4}ms{:>+12.4}ms{:>+9.1}%  {}\n",
+                    c.day,
+                    c.baseline_ms,
+                    c.current_ms,
+                    c.delta_ms,
+                    c.change_percent,
+                    status(c)
+                ));
+            }
+            res
+        }
+    }
+}
+
+/// every phase of every benchmark report, in order
+fn bench_rows(reports: &[BenchReport]) -> impl Iterator<Item = (&BenchReport, &str, &Stats)> {
+    reports.iter().flat_map(|report| {
+        [
+            (report, "parse", &report.parse),
+            (report, "part_1", &report.part_1),
+            (report, "part_2", &report.part_2),
+            (report, "total", &report.total),
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{csv_field, render, render_bench, OutputFormat};
+    use crate::{
+        bench::{BenchReport, Stats},
+        utils::solver_types::SolveReport,
+    };
+
+    fn reports() -> Vec<SolveReport> {
+        vec![
+            SolveReport {
+                day: 1,
+                part_1: Some("24000".to_string()),
+                part_2: Some("45000".to_string()),
+                parse_time: Duration::from_millis(1),
+                part_1_time: Duration::from_millis(2),
+                solved_together: true,
+                ..Default::default()
+            },
+            SolveReport {
+                day: 5,
+                part_1: Some("C,M\"Z".to_string()),
+                part_2: Some("\nab|\ncd\n".to_string()),
+                parse_time: Duration::from_millis(1),
+                part_1_time: Duration::from_millis(2),
+                part_2_time: Duration::from_millis(3),
+                ..Default::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn test_json() {
+        let result: serde_json::Value =
+            serde_json::from_str(&render(&reports(), None, OutputFormat::Json)).unwrap();
+
+        assert_eq!(9.0, result["total_ms"]);
+        assert!(result.get("wall_ms").is_none());
+        assert_eq!("24000", result["days"][0]["part_1"]);
+        assert_eq!(5, result["days"][1]["day"]);
+        assert_eq!(6.0, result["days"][1]["total_ms"]);
+    }
+
+    #[test]
+    fn test_text() {
+        // a single day looks like a run of many
+        let result = render(
+            &reports()[1..],
+            Some(Duration::from_millis(7)),
+            OutputFormat::Text,
+        );
+
+        assert_eq!(Some("Day 05:"), result.lines().nth(1));
+        assert_eq!(
+            Some("Solved all problems in: 6ms"),
+            result.lines().nth(result.lines().count() - 2)
+        );
+        assert_eq!(Some("Wall-clock time:\t7ms"), result.lines().last());
+    }
+
+    #[test]
+    fn test_csv() {
+        let expected = "day,part_1,part_2,parse_ms,part_1_ms,part_2_ms,total_ms,solved_together
+1,24000,45000,1,2,0,3,true
+5,\"C,M\"\"Z\",\"
+ab|
+cd
+\",1,2,3,6,false
+";
+
+        assert_eq!(expected, render(&reports(), None, OutputFormat::Csv));
+        assert_eq!("\"a\rb\"", csv_field("a\rb"));
+    }
+
+    #[test]
+    fn test_markdown() {
+        let result = render(
+            &reports(),
+            Some(Duration::from_millis(4)),
+            OutputFormat::Markdown,
+        );
+
+        assert_eq!(
+            Some("| 05 | C,M\"Z | ab\\|<br>cd | 1.000 | 2.000 | 3.000 | 6.000 |"),
+            result.lines().nth(3)
+        );
+        assert_eq!(
+            Some("| **Total** | | | | | | 9.000 |"),
+            result.lines().nth(4)
+        );
+        assert_eq!(
+            Some("| **Wall-clock** | | | | | | 4.000 |"),
+            result.lines().last()
+        );
+    }
+
+    #[test]
+    fn test_bench_csv() {
+        let stats = Stats {
+            min: 1.0,
+            median: 2.0,
+            mean: 2.5,
+            p95: 4.0,
+            stddev: 0.5,
+        };
+        let report = BenchReport {
+            day: 4,
+            iterations: 10,
+            total: stats,
+            ..Default::default()
+        };
+
+        let result = render_bench(&[report], OutputFormat::Csv);
+
+        assert_eq!(5, result.lines().count());
+        assert_eq!(Some("4,parse,10,0,0,0,0,0"), result.lines().nth(1));
+        assert_eq!(Some("4,total,10,1,2,2.5,4,0.5"), result.lines().nth(4));
+    }
+}
@@ -0,0 +1,73 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// Apply a function to every item using up to `jobs` worker threads.
+/// Results are returned in the same order as the items, regardless of which finished first
+pub fn map_parallel<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let workers = jobs.clamp(1, items.len().max(1));
+
+    if workers == 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new((0..items.len()).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                let result = f(item);
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("every item is processed by a worker"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::map_parallel;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1)]
+    #[case(3)]
+    #[case(100)]
+    fn validate_order(#[case] jobs: usize) {
+        let items = (0..20u64).collect::<Vec<_>>();
+
+        let result = map_parallel(&items, jobs, |i| {
+            // make early items finish last
+            thread::sleep(Duration::from_millis(20 - i));
+            i * 2
+        });
+
+        assert_eq!((0..20).map(|i| i * 2).collect::<Vec<_>>(), result);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(map_parallel(&Vec::<u8>::new(), 4, |i| *i).is_empty());
+    }
+}
@@ -0,0 +1,117 @@
+//! Solutions register themselves here with `inventory::submit!`, so days and years can be added in any order
+
+use clap::ValueEnum;
+use itertools::Itertools;
+
+use crate::utils::solver_types::Solution;
+
+/// Which solver trait a day implements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SolutionKind {
+    Linear,
+    Simultaneous,
+}
+
+impl SolutionKind {
+    pub fn trait_name(&self) -> &'static str {
+        match self {
+            SolutionKind::Linear => "SolutionLinear",
+            SolutionKind::Simultaneous => "SolutionSimultaneous",
+        }
+    }
+
+    /// the function that adapts this kind of day to `Solution`
+    pub fn adapter(&self) -> &'static str {
+        match self {
+            SolutionKind::Linear => "linear",
+            SolutionKind::Simultaneous => "simultaneous",
+        }
+    }
+}
+
+/// A registered day's solution. Each `yYYYY/dayNN.rs` submits one of these
+pub struct Registration {
+    pub year: usize,
+    pub day: usize,
+    pub title: &'static str,
+    pub solution: &'static dyn Solution,
+}
+
+inventory::collect!(Registration);
+
+/// Every registered solution for a year, ordered by day
+pub fn solutions(year: usize) -> Vec<&'static Registration> {
+    let mut solutions = inventory::iter::<Registration>
+        .into_iter()
+        .filter(|s| s.year == year)
+        .collect::<Vec<_>>();
+    solutions.sort_by_key(|s| s.day);
+    solutions
+}
+
+/// Get a day's solution, if it's been solved
+///
+/// ```
+/// use aoc2022::utils::{params::Params, solver_types::{solve, Parts}};
+///
+/// let solution = aoc2022::registry::get(2022, 1).unwrap();
+/// assert_eq!("Calorie Counting", solution.title);
+///
+/// let report = solve(solution.solution, "1000\n\n2000\n\n3000", Parts::One, &Params::default()).unwrap();
+/// assert_eq!(Some("3000".to_string()), report.part_1);
+/// assert_eq!(None, report.part_2);
+/// ```
+pub fn get(year: usize, day: usize) -> Option<&'static Registration> {
+    inventory::iter::<Registration>
+        .into_iter()
+        .find(|s| s.year == year && s.day == day)
+}
+
+/// The days of a year that have solutions, in order
+pub fn solved_days(year: usize) -> Vec<usize> {
+    solutions(year).iter().map(|s| s.day).collect()
+}
+
+/// The most recently solved day of a year
+pub fn latest(year: usize) -> Option<usize> {
+    solutions(year).last().map(|s| s.day)
+}
+
+/// The years that have any solutions, in order
+pub fn years() -> Vec<usize> {
+    inventory::iter::<Registration>
+        .into_iter()
+        .map(|s| s.year)
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+/// The most recent year with any solutions
+pub fn latest_year() -> Option<usize> {
+    years().last().copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::{get, latest, latest_year, solved_days, years};
+
+    #[test]
+    fn test_registry() {
+        let days = solved_days(2022);
+
+        assert!(days.iter().all_unique(), "a day is registered twice");
+        assert_eq!(Some(1), days.first().copied());
+        assert_eq!(days.last().copied(), latest(2022));
+
+        assert_eq!(1, get(2022, 1).unwrap().day);
+        assert!(get(2022, 0).is_none());
+        assert!(get(2022, 26).is_none());
+        assert!(get(2015, 1).is_none());
+
+        assert!(years().contains(&2022));
+        assert_eq!(years().last().copied(), latest_year());
+    }
+}
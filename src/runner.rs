@@ -0,0 +1,326 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::Path,
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    registry::{self, Registration},
+    utils::{
+        cancel::{set_current_token, CancellationToken},
+        params::Params,
+        solver_types::{solve, Parts, SolveReport},
+    },
+};
+
+/// The input path that means "read from stdin"
+pub const STDIN_PATH: &str = "-";
+
+/// Load a puzzle input from a file
+pub fn load_from_file(file_path: &Path) -> Result<String> {
+    if !file_path.is_file() {
+        return Err(anyhow!(
+            "input path '{:?}' is not valid. Please provide a path to a valid text file.",
+            file_path.to_str()
+        ));
+    }
+
+    match fs::read_to_string(file_path) {
+        Err(error) => Err(anyhow!(
+            "Failed to read file. Reason: {}",
+            error.to_string()
+        )),
+        Ok(text) => Ok(text),
+    }
+}
+
+/// Load a puzzle input from everything written to stdin
+pub fn load_from_stdin() -> Result<String> {
+    let mut text = String::new();
+
+    io::stdin()
+        .read_to_string(&mut text)
+        .map_err(|error| anyhow!("Failed to read stdin. Reason: {}", error))?;
+
+    Ok(text)
+}
+
+/// get a day's solution, or an error if it isn't registered
+pub fn solution_for(year: usize, day: usize) -> Result<&'static Registration> {
+    registry::get(year, day)
+        .ok_or_else(|| anyhow!("Day '{}' of {} is invalid or not yet solved", day, year))
+}
+
+/// load a day's input, from stdin if the path is `-`
+pub fn load_input(input_path: &str) -> Result<String> {
+    match input_path {
+        STDIN_PATH => load_from_stdin(),
+        path => load_from_file(Path::new(path)),
+    }
+}
+
+/// run the specified parts of a single day's solution, with the given params
+pub fn run_single(
+    year: usize,
+    day: usize,
+    input_path: &str,
+    parts: Parts,
+    params: &Params,
+) -> Result<SolveReport> {
+    let registration = solution_for(year, day)?;
+    let input = load_input(input_path)?;
+
+    let report = solve(registration.solution, &input, parts, params)?;
+
+    Ok(SolveReport { day, ..report })
+}
+
+/// How an attempt to solve a day ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayOutcome {
+    Solved(SolveReport),
+    Failed(String),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Display for DayOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayOutcome::Solved(_) => write!(f, "SOLVED"),
+            DayOutcome::Failed(reason) => write!(f, "FAILED ({})", reason),
+            DayOutcome::Panicked(message) => write!(f, "PANICKED ({})", message),
+            DayOutcome::TimedOut(after) => write!(f, "TIMEOUT (after {}s)", after.as_secs_f32()),
+        }
+    }
+}
+
+/// get the message a panic was raised with, if it has one
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "no panic message".to_string(),
+        },
+    }
+}
+
+/// run a single day's solution, catching any errors or panics so other days can still be run
+pub fn run_guarded(
+    year: usize,
+    day: usize,
+    input_path: &str,
+    parts: Parts,
+    params: &Params,
+) -> DayOutcome {
+    match catch_unwind(AssertUnwindSafe(|| {
+        run_single(year, day, input_path, parts, params)
+    })) {
+        Ok(Ok(report)) => DayOutcome::Solved(report),
+        Ok(Err(err)) => DayOutcome::Failed(err.to_string()),
+        Err(payload) => DayOutcome::Panicked(panic_message(payload)),
+    }
+}
+
+/// run a solve on a worker thread, giving up on it after `timeout`.
+/// When it times out the solve's cancellation token is triggered, and the worker is left to finish on its own
+fn with_timeout(
+    solve: impl FnOnce() -> DayOutcome + Send + 'static,
+    timeout: Duration,
+) -> DayOutcome {
+    let token = CancellationToken::default();
+    let worker_token = token.clone();
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        set_current_token(worker_token);
+        // the receiver is gone if we timed out, so there's nobody to tell
+        let _ = sender.send(solve());
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(outcome) => outcome,
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            token.cancel();
+            DayOutcome::TimedOut(timeout)
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            DayOutcome::Failed("Solve thread stopped without reporting".to_string())
+        }
+    }
+}
+
+/// parse a timeout given in seconds, which must be positive and small enough to fit in a `Duration`
+pub fn parse_timeout(seconds: &str) -> Result<Duration> {
+    seconds
+        .trim()
+        .parse::<f32>()
+        .ok()
+        .filter(|secs| *secs > 0.0 && *secs < u64::MAX as f32)
+        .map(Duration::from_secs_f32)
+        .ok_or_else(|| {
+            anyhow!(
+                "Invalid timeout '{}'. Expected a positive number of seconds",
+                seconds
+            )
+        })
+}
+
+/// run a single day's solution like `run_guarded`, reporting a timeout if it takes longer than `timeout`
+pub fn run_with_timeout(
+    year: usize,
+    day: usize,
+    input_path: &str,
+    parts: Parts,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> DayOutcome {
+    match timeout {
+        None => run_guarded(year, day, input_path, parts, params),
+        Some(timeout) => {
+            let input_path = input_path.to_string();
+            let params = params.clone();
+            with_timeout(
+                move || run_guarded(year, day, &input_path, parts, &params),
+                timeout,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        panic::catch_unwind,
+        sync::mpsc,
+        time::{Duration, Instant},
+    };
+
+    use super::{
+        load_from_file, panic_message, parse_timeout, run_guarded, with_timeout, DayOutcome,
+    };
+    use crate::utils::{cancel::check_cancelled, params::Params, solver_types::Parts};
+    use rstest::rstest;
+
+    #[test]
+    fn test_panic_message() {
+        let formatted = catch_unwind(|| panic!("day {} broke", 11)).unwrap_err();
+        assert_eq!("day 11 broke", panic_message(formatted));
+
+        let literal = catch_unwind(|| panic!("invaliid input")).unwrap_err();
+        assert_eq!("invaliid input", panic_message(literal));
+    }
+
+    #[rstest]
+    #[case("1.5", Some(Duration::from_millis(1500)))]
+    #[case(" 2 ", Some(Duration::from_secs(2)))]
+    #[case("0", None)]
+    #[case("-1", None)]
+    #[case("NaN", None)]
+    #[case("inf", None)]
+    #[case("1e30", None)]
+    #[case("soon", None)]
+    fn validate_timeout(#[case] seconds: &str, #[case] expected: Option<Duration>) {
+        assert_eq!(expected, parse_timeout(seconds).ok());
+    }
+
+    #[test]
+    fn test_load_any_extension() {
+        let input = env::temp_dir().join("aoc2022_runner_input.in");
+        fs::write(&input, "1000\n2000").unwrap();
+
+        assert_eq!("1000\n2000", load_from_file(&input).unwrap());
+        assert!(load_from_file(&env::temp_dir()).is_err());
+    }
+
+    #[test]
+    fn test_run_guarded() {
+        assert!(matches!(
+            run_guarded(2022, 0, "./inputs/2022/input_00.txt", Parts::Both, &Params::default()),
+            DayOutcome::Failed(reason) if reason.contains("not yet solved")
+        ));
+        assert!(matches!(
+            run_guarded(
+                2022,
+                1,
+                "./does/not/exist.txt",
+                Parts::Both,
+                &Params::default()
+            ),
+            DayOutcome::Failed(_)
+        ));
+
+        let bad_input = env::temp_dir().join("aoc2022_runner_bad_input.txt");
+        fs::write(&bad_input, "Q Y").unwrap();
+
+        assert_eq!(
+            DayOutcome::Failed(
+                "Invalid input on line 1, column 1: expected A, B or C\n1 | Q Y\n  | ^".to_string()
+            ),
+            run_guarded(
+                2022,
+                2,
+                bad_input.to_str().unwrap(),
+                Parts::Both,
+                &Params::default()
+            )
+        );
+
+        let input = env::temp_dir().join("aoc2022_runner_part_input.txt");
+        fs::write(&input, "1000\n\n2000\n\n3000").unwrap();
+
+        match run_guarded(
+            2022,
+            1,
+            input.to_str().unwrap(),
+            Parts::Two,
+            &Params::default(),
+        ) {
+            DayOutcome::Solved(report) => {
+                assert_eq!(None, report.part_1);
+                assert_eq!(Some("6000".to_string()), report.part_2);
+            }
+            outcome => panic!("day 1 wasn't solved: {}", outcome),
+        }
+    }
+
+    #[test]
+    fn test_timeout() {
+        let (sender, receiver) = mpsc::channel();
+        let start = Instant::now();
+
+        let outcome = with_timeout(
+            move || loop {
+                if let Err(err) = check_cancelled() {
+                    sender.send(err.to_string()).unwrap();
+                    return DayOutcome::Failed(err.to_string());
+                }
+            },
+            Duration::from_millis(50),
+        );
+
+        assert_eq!(DayOutcome::TimedOut(Duration::from_millis(50)), outcome);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        // the solve noticed it was cancelled and stopped
+        assert_eq!(
+            "Solve was cancelled",
+            receiver.recv_timeout(Duration::from_secs(5)).unwrap()
+        );
+
+        let quick = with_timeout(
+            || DayOutcome::Failed("done".to_string()),
+            Duration::from_secs(5),
+        );
+        assert_eq!(DayOutcome::Failed("done".to_string()), quick);
+    }
+}
@@ -0,0 +1,282 @@
+use std::{fs, path::Path};
+
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{examples::example_dir, registry::SolutionKind, selection::DAYS};
+
+/// Default location of the solution files, relative to the repo root
+pub const DEFAULT_SOLUTIONS_DIR: &str = "./src/solutions";
+
+const YEAR_MOD_HEADER: &str =
+    "// each day registers itself with `crate::registry`, so it only needs declaring here\n";
+
+const LINEAR_TEMPLATE: &str = include_str!("solutions/templates/linear_template.rs");
+const SIMULTANEOUS_TEMPLATE: &str = include_str!("solutions/templates/simultaneous_template.rs");
+
+lazy_static! {
+    static ref EXAMPLE_COMMENT: Regex = Regex::new(r"(?m)^// Example:\n(^//.*\n)*\n?").unwrap();
+    static ref TEST_CASE: Regex = Regex::new(r#"#\[case\("(.*)", (\d+), (\d+)\)\]"#).unwrap();
+    static ref DAY_MOD: Regex = Regex::new(r"(?m)^mod day(\d+);\n").unwrap();
+    static ref YEAR_MOD: Regex = Regex::new(r"(?m)^pub mod y(\d+);\n").unwrap();
+}
+
+/// the template for a kind of solution, and the name of its example struct
+fn template(kind: SolutionKind) -> (&'static str, &'static str) {
+    match kind {
+        SolutionKind::Linear => (LINEAR_TEMPLATE, "ExampleSolutionLinear"),
+        SolutionKind::Simultaneous => (SIMULTANEOUS_TEMPLATE, "ExampleSolutionSimultaneous"),
+    }
+}
+
+/// the template's first example, as the contents of an example input and its `answers.toml`
+fn example(template: &str) -> Result<(String, String)> {
+    let example = TEST_CASE
+        .captures(template)
+        .ok_or_else(|| anyhow!("Template has no example test case"))?;
+
+    Ok((
+        example[1].to_string(),
+        format!(
+            "# TODO: replace with the puzzle's example\n[example]\npart1 = {}\npart2 = {}\n",
+            &example[2], &example[3]
+        ),
+    ))
+}
+
+/// Generate the source of a new day's solution from one of the templates
+pub fn generate(year: usize, day: usize, title: &str, kind: SolutionKind) -> Result<String> {
+    let (template, example_name) = template(kind);
+    let trait_name = kind.trait_name();
+    let (source, _) = template
+        .split_once("#[cfg(test)]")
+        .ok_or_else(|| anyhow!("Template has no test module"))?;
+
+    let source = source
+        .replacen(
+            "use crate::",
+            "use crate::registry::Registration;\nuse crate::",
+            1,
+        )
+        .replace(
+            &format!("use crate::utils::solver_types::{};", trait_name),
+            &format!(
+                "use crate::utils::solver_types::{{{}, {}}};",
+                kind.adapter(),
+                trait_name
+            ),
+        )
+        .replace("use anyhow::anyhow;", "use anyhow::{anyhow, Result};")
+        .replace("anyhow::Result<", "Result<")
+        .replace(example_name, &format!("Day{}Solution", day));
+    let source = EXAMPLE_COMMENT.replace(&source, "");
+
+    // the registration goes at the end of the file, like the existing days
+    let registration = format!(
+        r#"inventory::submit! {{
+    Registration {{
+        year: {year},
+        day: {day},
+        title: {title:?},
+        solution: &{adapter}::<Day{day}Solution, _, _, _>(),
+    }}
+}}
+"#,
+        adapter = kind.adapter(),
+    );
+
+    Ok(format!("{}\n\n{}", source.trim_end(), registration))
+}
+
+/// Insert a `mod` declaration numbered `n` among the existing ones matched by `existing`, keeping them in order.
+/// If there aren't any yet, it goes at the end
+fn declare(source: &str, existing: &Regex, n: usize, declaration: &str) -> Result<String> {
+    let mods = existing
+        .captures_iter(source)
+        .map(|caps| (caps.get(0).unwrap(), caps[1].parse::<usize>().unwrap()))
+        .collect::<Vec<_>>();
+
+    if mods.iter().any(|(_, existing)| *existing == n) {
+        return Err(anyhow!("'{}' is already declared", declaration.trim()));
+    }
+
+    let insert_at = mods
+        .iter()
+        .find(|(_, existing)| *existing > n)
+        .map(|(m, _)| m.start())
+        .or_else(|| mods.last().map(|(m, _)| m.end()))
+        .unwrap_or(source.len());
+
+    Ok(format!(
+        "{}{}{}",
+        &source[..insert_at],
+        declaration,
+        &source[insert_at..]
+    ))
+}
+
+/// Add a day's `mod` declaration to the source of a year's `mod.rs`
+pub fn register(mod_source: &str, day: usize) -> Result<String> {
+    declare(mod_source, &DAY_MOD, day, &format!("mod day{:02};\n", day))
+}
+
+/// Add a year's `mod` declaration to the source of `solutions/mod.rs`
+pub fn register_year(mod_source: &str, year: usize) -> Result<String> {
+    declare(
+        mod_source,
+        &YEAR_MOD,
+        year,
+        &format!("pub mod y{};\n", year),
+    )
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .map_err(|error| anyhow!("Failed to read '{}'. Reason: {}", path.display(), error))
+}
+
+/// Create `yYYYY/dayNN.rs` in the solutions directory and declare it, creating the year's module if needed.
+/// The template's example is added to the examples directory, unless the day already has examples
+pub fn scaffold(
+    solutions_dir: &Path,
+    examples_dir: &Path,
+    year: usize,
+    day: usize,
+    title: &str,
+    kind: SolutionKind,
+) -> Result<()> {
+    if !DAYS.contains(&day) {
+        return Err(anyhow!(
+            "'{}' is not a valid day. Expected {} to {}",
+            day,
+            DAYS.start(),
+            DAYS.end()
+        ));
+    }
+
+    let year_dir = solutions_dir.join(format!("y{}", year));
+    let day_path = year_dir.join(format!("day{:02}.rs", day));
+    let mod_path = year_dir.join("mod.rs");
+
+    if day_path.exists() {
+        return Err(anyhow!("'{}' already exists", day_path.display()));
+    }
+
+    if !mod_path.exists() {
+        let solutions_mod_path = solutions_dir.join("mod.rs");
+        let registered = register_year(&read(&solutions_mod_path)?, year)?;
+
+        fs::create_dir_all(&year_dir)?;
+        fs::write(&mod_path, YEAR_MOD_HEADER)?;
+        fs::write(&solutions_mod_path, registered)?;
+    }
+
+    let registered = register(&read(&mod_path)?, day)?;
+
+    fs::write(&day_path, generate(year, day, title, kind)?)?;
+    fs::write(&mod_path, registered)?;
+
+    let example_dir = example_dir(examples_dir, year, day);
+    if !example_dir.exists() {
+        let (input, answers) = example(template(kind).0)?;
+
+        fs::create_dir_all(&example_dir)?;
+        fs::write(example_dir.join("example.txt"), input)?;
+        fs::write(example_dir.join("answers.toml"), answers)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::{example, generate, register, register_year, scaffold, LINEAR_TEMPLATE};
+    use crate::registry::SolutionKind;
+    use rstest::rstest;
+
+    const MOD_SOURCE: &str = "// each day registers itself
+mod day01;
+mod day02;
+mod day05;
+";
+
+    #[rstest]
+    #[case(SolutionKind::Linear, "linear::<Day7Solution, _, _, _>()")]
+    #[case(SolutionKind::Simultaneous, "simultaneous::<Day7Solution, _, _, _>()")]
+    fn validate_generate(#[case] kind: SolutionKind, #[case] adapter: &str) {
+        let source = generate(2022, 7, "No Space Left On Device", kind).unwrap();
+
+        assert!(source.contains("pub struct Day7Solution {}"));
+        assert!(source.contains(&format!(
+            "        year: 2022,\n        day: 7,\n        title: \"No Space Left On Device\",\n        solution: &{},",
+            adapter
+        )));
+        assert!(!source.contains("#[cfg(test)]"));
+        assert!(!source.contains("Example"));
+        assert!(source.trim_end().ends_with("\n    }\n}"));
+        assert!(
+            source.find("inventory::submit!")
+                > source.find(&format!("\nimpl {}", kind.trait_name()))
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let (input, answers) = example(LINEAR_TEMPLATE).unwrap();
+
+        assert_eq!("[1,2,3]", input);
+        assert!(answers.contains("[example]\npart1 = 6\npart2 = 18\n"));
+        assert!(toml::from_str::<toml::Table>(&answers).is_ok());
+    }
+
+    #[rstest]
+    #[case(3, "mod day01;\nmod day02;\nmod day03;\nmod day05;\n")]
+    #[case(22, "mod day01;\nmod day02;\nmod day05;\nmod day22;\n")]
+    fn validate_register(#[case] day: usize, #[case] expected: &str) {
+        let registered = register(MOD_SOURCE, day).unwrap();
+
+        assert_eq!(
+            format!("// each day registers itself\n{}", expected),
+            registered
+        );
+    }
+
+    #[test]
+    fn test_register_existing() {
+        assert!(register(MOD_SOURCE, 5).is_err());
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(26)]
+    fn validate_scaffold_bad_day(#[case] day: usize) {
+        let dir = env::temp_dir().join(format!("aoc2022_scaffold_{}", day));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("mod.rs"), "pub mod y2022;\n").unwrap();
+
+        let result = scaffold(&dir, &dir, 2023, day, "Day", SolutionKind::Linear);
+
+        assert_eq!(
+            format!("'{}' is not a valid day. Expected 1 to 25", day),
+            result.unwrap_err().to_string()
+        );
+        assert!(!dir.join("y2023").exists());
+    }
+
+    #[test]
+    fn test_register_year() {
+        let source = "// each year registers itself\npub(crate) mod templates;\npub mod y2022;\n";
+
+        assert_eq!(
+            "// each year registers itself\npub(crate) mod templates;\npub mod y2022;\npub mod y2023;\n",
+            register_year(source, 2023).unwrap()
+        );
+        assert_eq!(
+            "// each day registers itself\nmod day01;\n",
+            register("// each day registers itself\n", 1).unwrap()
+        );
+    }
+}
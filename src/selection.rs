@@ -0,0 +1,143 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use anyhow::{anyhow, Error, Result};
+use itertools::Itertools;
+
+/// The default location of a day's input
+pub const DEFAULT_INPUT_PATTERN: &str = "./inputs/{year}/input_{day:02}.txt";
+/// The default location of a year's known answers
+pub const DEFAULT_ANSWERS_PATTERN: &str = "./answers/{year}.toml";
+
+/// The days of an AoC event
+pub const DAYS: RangeInclusive<usize> = 1..=25;
+
+/// Parse a single day, which must be from 1 to 25
+///
+/// ```
+/// use aoc2022::parse_day;
+///
+/// assert_eq!(7, parse_day("7").unwrap());
+/// assert!(parse_day("26").is_err());
+/// ```
+pub fn parse_day(s: &str) -> Result<usize> {
+    s.trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|day| DAYS.contains(day))
+        .ok_or_else(|| {
+            anyhow!(
+                "'{}' is not a valid day. Expected {} to {}",
+                s.trim(),
+                DAYS.start(),
+                DAYS.end()
+            )
+        })
+}
+
+/// A sorted set of days, parsed from a list of days and inclusive ranges like `3,7,10-14`
+///
+/// ```
+/// use aoc2022::DaySet;
+///
+/// let days: DaySet = "10-12,3".parse().unwrap();
+/// assert_eq!(vec![3, 10, 11, 12], days.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet(pub Vec<usize>);
+
+impl FromStr for DaySet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut days = vec![];
+
+        for part in s.split(',').map(str::trim) {
+            match part.split_once('-') {
+                None => days.push(parse_day(part)?),
+                Some((from, to)) => {
+                    let (from, to) = (parse_day(from)?, parse_day(to)?);
+                    if from > to {
+                        return Err(anyhow!("day range '{}' is backwards", part));
+                    }
+                    days.extend(from..=to);
+                }
+            }
+        }
+
+        Ok(Self(days.into_iter().sorted().dedup().collect()))
+    }
+}
+
+/// Fill in the year in a path pattern. Supports `{year}`
+pub fn year_path(pattern: &str, year: usize) -> String {
+    pattern.replace("{year}", &year.to_string())
+}
+
+/// Fill in the year and day in an input path pattern. Supports `{year}`, `{day}` and zero-padded `{day:02}`
+pub fn input_path(pattern: &str, year: usize, day: usize) -> String {
+    year_path(pattern, year)
+        .replace("{day:02}", &format!("{:02}", day))
+        .replace("{day}", &day.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        input_path, parse_day, year_path, DaySet, DEFAULT_ANSWERS_PATTERN, DEFAULT_INPUT_PATTERN,
+    };
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("3,7,10-14", vec![3, 7, 10, 11, 12, 13, 14])]
+    #[case("5", vec![5])]
+    #[case("12-12, 4 ,1-3,2", vec![1, 2, 3, 4, 12])]
+    fn validate_day_set(#[case] input: &str, #[case] expected: Vec<usize>) {
+        assert_eq!(DaySet(expected), input.parse().unwrap());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("3,x")]
+    #[case("14-10")]
+    #[case("1-")]
+    #[case("0")]
+    #[case("24-26")]
+    #[case("1-18446744073709551615")]
+    fn validate_bad_day_set(#[case] input: &str) {
+        assert!(input.parse::<DaySet>().is_err());
+    }
+
+    #[test]
+    fn test_day_out_of_range() {
+        assert_eq!(
+            "'4000000000' is not a valid day. Expected 1 to 25",
+            "1-4000000000".parse::<DaySet>().unwrap_err().to_string()
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("x")]
+    #[case("0")]
+    #[case("26")]
+    #[case("-1")]
+    fn validate_bad_day(#[case] input: &str) {
+        assert!(parse_day(input).is_err());
+    }
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(
+            "./inputs/2022/input_07.txt",
+            input_path(DEFAULT_INPUT_PATTERN, 2022, 7)
+        );
+        assert_eq!(
+            "./my_inputs/day7/07.txt",
+            input_path("./my_inputs/day{day}/{day:02}.txt", 2022, 7)
+        );
+        assert_eq!(
+            "./answers/2023.toml",
+            year_path(DEFAULT_ANSWERS_PATTERN, 2023)
+        );
+    }
+}
@@ -1,43 +1,3 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-pub mod templates;
-
-use anyhow::Result;
-
-/// Add new solutions to this const
-pub const SOLUTIONS: [fn(&str) -> Result<f32>; 18] = [
-    day01::day01,
-    day02::day02,
-    day03::day03,
-    day04::day04,
-    day05::day05,
-    day06::day06,
-    day07::day07,
-    day08::day08,
-    day09::day09,
-    day10::day10,
-    day11::day11,
-    day12::day12,
-    day13::day13,
-    day14::day14,
-    day15::day15,
-    day16::day16,
-    day17::day17,
-    day18::day18,
-];
+// each year's days register themselves with `crate::registry`, so they only need declaring here
+pub(crate) mod templates;
+pub mod y2022;
@@ -1,3 +1,4 @@
+use crate::utils::parse::Source;
 use crate::utils::solver_types::SolutionLinear;
 use anyhow::Result;
 
@@ -9,12 +10,15 @@ pub struct ExampleSolutionLinear {}
 
 impl SolutionLinear<Vec<usize>, usize, usize> for ExampleSolutionLinear {
     fn load(input: &str) -> Result<Vec<usize>> {
+        let source = Source(input);
+
         Ok(input
-            .replace(['[', ']'], "")
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
             .split(',')
-            .into_iter()
-            .map(|num| num.parse().unwrap())
-            .collect())
+            .map(|num| source.parse(num, "a number"))
+            .collect::<Result<_, _>>()?)
     }
 
     fn part1(input: &mut Vec<usize>) -> Result<usize> {
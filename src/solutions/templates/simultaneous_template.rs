@@ -1,3 +1,4 @@
+use crate::utils::parse::Source;
 use crate::utils::solver_types::SolutionSimultaneous;
 use anyhow::anyhow;
 use itertools::Itertools;
@@ -12,12 +13,15 @@ pub struct ExampleSolutionSimultaneous {}
 
 impl SolutionSimultaneous<Vec<usize>, usize, usize> for ExampleSolutionSimultaneous {
     fn load(input: &str) -> anyhow::Result<Vec<usize>> {
+        let source = Source(input);
+
         Ok(input
-            .replace(['[', ']'], "")
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
             .split(',')
-            .into_iter()
-            .map(|num| num.parse().unwrap())
-            .collect())
+            .map(|num| source.parse(num, "a number"))
+            .collect::<Result<_, _>>()?)
     }
 
     fn solve(input: Vec<usize>) -> anyhow::Result<(usize, usize)> {
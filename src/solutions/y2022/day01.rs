@@ -0,0 +1,47 @@
+use crate::registry::Registration;
+use crate::utils::{
+    load_input::load_segmented_lines,
+    parse::Source,
+    solver_types::{simultaneous, SolutionSimultaneous},
+};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+pub struct Day1Solution {}
+
+impl SolutionSimultaneous<Vec<Vec<u32>>, u32, u32> for Day1Solution {
+    fn load(input: &str) -> Result<Vec<Vec<u32>>> {
+        let source = Source(input);
+
+        Ok(load_segmented_lines(input, "\n\n", |x| {
+            source.parse(x, "a number of calories")
+        })?)
+    }
+
+    fn solve(input: Vec<Vec<u32>>) -> Result<(u32, u32)> {
+        let sorted = input
+            .iter()
+            .map(|v| v.iter().sum())
+            .sorted()
+            .rev()
+            .collect::<Vec<_>>();
+
+        if sorted.len() < 3 {
+            return Err(anyhow!(
+                "Expected at least 3 elves, but found {}",
+                sorted.len()
+            ));
+        }
+
+        Ok((sorted[0], sorted[0] + sorted[1] + sorted[2]))
+    }
+}
+
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 1,
+        title: "Calorie Counting",
+        solution: &simultaneous::<Day1Solution, _, _, _>(),
+    }
+}
@@ -1,4 +1,8 @@
-use crate::utils::solver_types::{solve_simultaneous, SolutionSimultaneous};
+use crate::registry::Registration;
+use crate::utils::{
+    parse::{ParseError, Source},
+    solver_types::{simultaneous, SolutionSimultaneous},
+};
 use anyhow::Result;
 
 //not yet implemented
@@ -55,25 +59,28 @@ fn game2((them, strat): &(Rps, Rps)) -> usize {
 
 impl SolutionSimultaneous<Vec<(Rps, Rps)>, usize, usize> for Day2Solution {
     fn load(input: &str) -> Result<Vec<(Rps, Rps)>> {
+        let source = Source(input);
+
         Ok(input
             .lines()
             .map(|l| {
-                (
-                    match l.chars().next().unwrap() {
-                        'A' => Rps::Rock,
-                        'B' => Rps::Paper,
-                        'C' => Rps::Scissors,
-                        _ => panic!("invaliid input"),
+                let (them, us) = source.split_once(l, " ")?;
+                Ok((
+                    match them {
+                        "A" => Rps::Rock,
+                        "B" => Rps::Paper,
+                        "C" => Rps::Scissors,
+                        _ => return Err(source.error(them, "A, B or C")),
                     },
-                    match l.chars().nth(2).unwrap() {
-                        'X' => Rps::Rock,
-                        'Y' => Rps::Paper,
-                        'Z' => Rps::Scissors,
-                        _ => panic!("invaliid input"),
+                    match us {
+                        "X" => Rps::Rock,
+                        "Y" => Rps::Paper,
+                        "Z" => Rps::Scissors,
+                        _ => return Err(source.error(us, "X, Y or Z")),
                     },
-                )
+                ))
             })
-            .collect())
+            .collect::<Result<_, ParseError>>()?)
     }
 
     fn solve(input: Vec<(Rps, Rps)>) -> Result<(usize, usize)> {
@@ -83,29 +90,11 @@ impl SolutionSimultaneous<Vec<(Rps, Rps)>, usize, usize> for Day2Solution {
     }
 }
 
-pub fn day02(input: &str) -> Result<f32> {
-    solve_simultaneous::<Day2Solution, _, _, _>(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::Day2Solution;
-    use crate::utils::solver_types::SolutionSimultaneous;
-
-    #[test]
-    fn test_answer() {
-        // note: ugly format so we don't lead with a \n
-        let input = r#"A Y
-B X
-C Z"#;
-
-        let p1_expected = 15;
-        let p2_expected = 12;
-
-        let loaded = Day2Solution::load(input).unwrap();
-        let (p1, p2) = Day2Solution::solve(loaded).unwrap();
-
-        assert_eq!(p1_expected, p1);
-        assert_eq!(p2_expected, p2);
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 2,
+        title: "Rock Paper Scissors",
+        solution: &simultaneous::<Day2Solution, _, _, _>(),
     }
 }
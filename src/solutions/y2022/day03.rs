@@ -1,6 +1,10 @@
 use std::collections::HashSet;
 
-use crate::utils::solver_types::{solve_linear, SolutionLinear};
+use crate::registry::Registration;
+use crate::utils::{
+    parse::{ParseError, Source},
+    solver_types::{linear, SolutionLinear},
+};
 use anyhow::Result;
 use itertools::Itertools;
 
@@ -31,9 +35,18 @@ fn to_priority(c: &char) -> u32 {
 
 impl SolutionLinear<Vec<Sack>, u32, u32> for Day3Solution {
     fn load(input: &str) -> Result<Vec<Sack>> {
+        let source = Source(input);
+
         Ok(input
             .lines()
             .map(|line| {
+                if let Some(i) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                    return Err(source.error(&line[i..], "an item (a-z or A-Z)"));
+                }
+                if line.len() % 2 != 0 {
+                    return Err(source.error_after(line, "an even number of items"));
+                }
+
                 let cs = line.chars().collect_vec();
                 // populate left and right pockets
                 let mut l = HashSet::new();
@@ -45,9 +58,9 @@ impl SolutionLinear<Vec<Sack>, u32, u32> for Day3Solution {
                     r.insert(cs[i + mid]);
                 }
 
-                Sack::new(l, r)
+                Ok(Sack::new(l, r))
             })
-            .collect())
+            .collect::<Result<_, ParseError>>()?)
     }
 
     fn part1(input: &mut Vec<Sack>) -> Result<u32> {
@@ -73,33 +86,11 @@ impl SolutionLinear<Vec<Sack>, u32, u32> for Day3Solution {
     }
 }
 
-pub fn day03(input: &str) -> Result<f32> {
-    solve_linear::<Day3Solution, _, _, _>(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::Day3Solution;
-    use crate::utils::solver_types::SolutionLinear;
-
-    #[test]
-    fn test_answer() {
-        // note: ugly format so we don't lead with a \n
-        let input = r#"vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw"#;
-
-        let p1_expected = 157;
-        let p2_expected = 70;
-
-        let mut loaded = Day3Solution::load(input).unwrap();
-        let p1 = Day3Solution::part1(&mut loaded).unwrap();
-        let p2 = Day3Solution::part2(&mut loaded, p1).unwrap();
-
-        assert_eq!(p1_expected, p1);
-        assert_eq!(p2_expected, p2);
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 3,
+        title: "Rucksack Reorganization",
+        solution: &linear::<Day3Solution, _, _, _>(),
     }
 }
@@ -1,6 +1,8 @@
+use crate::registry::Registration;
 use crate::utils::{
     load_input::load_lines,
-    solver_types::{solve_linear, SolutionLinear},
+    parse::{ParseError, Source},
+    solver_types::{linear, SolutionLinear},
 };
 use anyhow::Result;
 
@@ -8,7 +10,7 @@ use anyhow::Result;
 pub struct Day4Solution {}
 
 /// create a u128 with 1 bits between the a-th and b-th bits inclusive
-/// we assume a b value no higher than 100
+/// we assume a b value no higher than 127
 fn to_bits(a: u32, b: u32) -> u128 {
     (a..b + 1).fold(0b0, |into, i| into | 1 << i)
 }
@@ -16,16 +18,22 @@ fn to_bits(a: u32, b: u32) -> u128 {
 impl SolutionLinear<Vec<(u128, u128)>, usize, usize> for Day4Solution {
     /// create bitmasks for sectors covered by each elf
     fn load(input: &str) -> Result<Vec<(u128, u128)>> {
-        Ok(load_lines(input, |line| {
-            let nums = line.split_once(',').unwrap();
-            let (a, b) = nums.0.split_once('-').unwrap();
-            let (x, y) = nums.1.split_once('-').unwrap();
+        let source = Source(input);
+        let section = |s: &str| match source.parse(s, "a section number")? {
+            n if n < 128 => Ok(n),
+            _ => Err(source.error(s, "a section no higher than 127")),
+        };
 
-            (
-                to_bits(a.parse().unwrap(), b.parse().unwrap()),
-                to_bits(x.parse().unwrap(), y.parse().unwrap()),
-            )
-        }))
+        Ok(load_lines(input, |line| {
+            let nums = source.split_once(line, ",")?;
+            let (a, b) = source.split_once(nums.0, "-")?;
+            let (x, y) = source.split_once(nums.1, "-")?;
+
+            Ok::<_, ParseError>((
+                to_bits(section(a)?, section(b)?),
+                to_bits(section(x)?, section(y)?),
+            ))
+        })?)
     }
 
     // mask left with right, and right with left, to see if value becomes 0 (one vec fully contains the other)
@@ -45,33 +53,11 @@ impl SolutionLinear<Vec<(u128, u128)>, usize, usize> for Day4Solution {
     }
 }
 
-pub fn day04(input: &str) -> Result<f32> {
-    solve_linear::<Day4Solution, _, _, _>(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::Day4Solution;
-    use crate::utils::solver_types::SolutionLinear;
-
-    #[test]
-    fn test_answer() {
-        // note: ugly format so we don't lead with a \n
-        let input = r#"2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8"#;
-
-        let p1_expected = 2;
-        let p2_expected = 4;
-
-        let mut loaded = Day4Solution::load(input).unwrap();
-        let p1 = Day4Solution::part1(&mut loaded).unwrap();
-        assert_eq!(p1_expected, p1);
-
-        let p2 = Day4Solution::part2(&mut loaded, p1).unwrap();
-        assert_eq!(p2_expected, p2);
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 4,
+        title: "Camp Cleanup",
+        solution: &linear::<Day4Solution, _, _, _>(),
     }
 }
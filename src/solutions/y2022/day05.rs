@@ -0,0 +1,141 @@
+use crate::registry::Registration;
+use crate::utils::{
+    parse::{ParseError, Source},
+    solver_types::{linear, SolutionLinear},
+};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+//not yet implemented
+pub struct Day5Solution {}
+
+type StacksAndArgs = (Vec<Vec<char>>, Vec<(usize, usize, usize)>); // move A from B to C
+
+/// the crate on top of each stack
+fn tops(stacks: &[Vec<char>]) -> Result<String> {
+    stacks
+        .iter()
+        .enumerate()
+        .map(|(i, stack)| {
+            stack
+                .last()
+                .ok_or_else(|| anyhow!("Stack {} ends up empty, so has no top crate", i + 1))
+        })
+        .collect()
+}
+
+impl SolutionLinear<StacksAndArgs, String, String> for Day5Solution {
+    fn load(input: &str) -> Result<StacksAndArgs> {
+        let source = Source(input);
+        let (l_stacks, l_instrs) = input
+            .split_once("\n\n")
+            .ok_or_else(|| source.error_after(input, "a blank line after the stacks"))?;
+
+        // get stacks
+        let stack_contents = l_stacks
+            .lines()
+            .map(|l| {
+                l.chars()
+                    .chunks(4)
+                    .into_iter()
+                    .map(|cs| match cs.into_iter().nth(1) {
+                        Some(' ') | None => None,
+                        c => c,
+                    })
+                    .collect::<Vec<Option<char>>>()
+            })
+            .collect_vec();
+
+        let n_stacks = stack_contents
+            .first()
+            .ok_or_else(|| source.error(l_stacks, "a drawing of the stacks"))?
+            .len();
+        let mut stacks = vec![vec![]; n_stacks];
+
+        for row in stack_contents.iter().take(n_stacks).rev() {
+            for (j, st) in stacks.iter_mut().enumerate().take(n_stacks) {
+                if let Some(Some(c)) = row.get(j) {
+                    st.push(*c)
+                }
+            }
+        }
+
+        let stack = |s| match source.parse::<usize>(s, "a stack number")? {
+            n if (1..=n_stacks).contains(&n) => Ok(n - 1),
+            _ => Err(source.error(s, format!("a stack between 1 and {}", n_stacks))),
+        };
+
+        // get instrs, tracking the stacks' heights so no move takes more crates than a stack holds
+        let mut heights = stacks.iter().map(Vec::len).collect_vec();
+        let instrs = l_instrs
+            .lines()
+            .map(|l| {
+                let mut words = l.split(' ');
+                let mut arg = || {
+                    words
+                        .nth(1)
+                        .ok_or_else(|| source.error_after(l, "'move N from A to B'"))
+                };
+
+                let count = arg()?;
+                let (n, from, to) = (
+                    source.parse::<usize>(count, "a number of crates")?,
+                    stack(arg()?)?,
+                    stack(arg()?)?,
+                );
+
+                if n > heights[from] {
+                    return Err(source.error(
+                        count,
+                        format!(
+                            "at most {} crates, as stack {} only holds that many",
+                            heights[from],
+                            from + 1
+                        ),
+                    ));
+                }
+                heights[from] -= n;
+                heights[to] += n;
+
+                Ok((n, from, to))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        Ok((stacks, instrs))
+    }
+
+    fn part1(input: &mut StacksAndArgs) -> Result<String> {
+        let mut stacks = input.0.clone();
+
+        // apply instrs, moving crates one at a time
+        for (n, from, to) in &input.1 {
+            let at = stacks[*from].len() - n;
+            let crates = stacks[*from].split_off(at);
+            stacks[*to].extend(crates.into_iter().rev());
+        }
+
+        tops(&stacks)
+    }
+
+    fn part2(input: &mut StacksAndArgs, _part_1_solution: String) -> Result<String> {
+        let mut stacks = input.0.clone();
+
+        // apply instrs, moving crates all at once
+        for (n, from, to) in &input.1 {
+            let at = stacks[*from].len() - n;
+            let crates = stacks[*from].split_off(at);
+            stacks[*to].extend(crates);
+        }
+
+        tops(&stacks)
+    }
+}
+
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 5,
+        title: "Supply Stacks",
+        solution: &linear::<Day5Solution, _, _, _>(),
+    }
+}
@@ -0,0 +1,42 @@
+use crate::registry::Registration;
+use crate::utils::solver_types::{linear, SolutionLinear};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+//not yet implemented
+pub struct Day6Solution {}
+
+fn first_with_n_unique(input: Vec<char>, n: usize) -> Result<usize> {
+    for (i, tup) in (0..).zip(input.windows(n)) {
+        if tup.iter().all_unique() {
+            return Ok(i + n);
+        }
+    }
+    Err(anyhow!(
+        "No {} unique characters in a row. Check input and try again",
+        n
+    ))
+}
+
+impl SolutionLinear<Vec<char>, usize, usize> for Day6Solution {
+    fn load(input: &str) -> Result<Vec<char>> {
+        Ok(input.trim_end().chars().collect())
+    }
+
+    fn part1(input: &mut Vec<char>) -> Result<usize> {
+        first_with_n_unique(input.to_vec(), 4)
+    }
+
+    fn part2(input: &mut Vec<char>, _part_1_solution: usize) -> Result<usize> {
+        first_with_n_unique(input.to_vec(), 14)
+    }
+}
+
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 6,
+        title: "Tuning Trouble",
+        solution: &linear::<Day6Solution, _, _, _>(),
+    }
+}
@@ -1,32 +1,51 @@
 use std::collections::HashMap;
 
-use crate::utils::solver_types::{solve_simultaneous, SolutionSimultaneous};
-use anyhow::Result;
+use crate::registry::Registration;
+use crate::utils::{
+    params::{Param, ParamInfo, Params},
+    parse::{ParseError, Source},
+    solver_types::{simultaneous, SolutionSimultaneous},
+};
+use anyhow::{anyhow, Result};
 use itertools::Itertools;
 
 //not yet implemented
 pub struct Day7Solution {}
 
+const DISK_SIZE: Param<usize> = Param::new("disk_size", 70_000_000);
+/// the free space the update needs
+const SPACE_NEEDED: Param<usize> = Param::new("space_needed", 30_000_000);
+
 #[derive(Default, Debug)]
 struct FileTree {
     visit_stack: Vec<String>,
     tree: HashMap<String, Vec<String>>,
     contents: HashMap<String, Vec<usize>>,
+    disk_size: usize,
+    space_needed: usize,
 }
 
 impl FileTree {
-    fn from(instrs: impl Iterator<Item = String>) -> Self {
+    fn from<'a>(
+        source: Source<'a>,
+        instrs: impl Iterator<Item = &'a str>,
+    ) -> Result<Self, ParseError> {
         let mut f_tree = FileTree::default();
 
         f_tree.visit_stack.push("/".to_string());
 
         for i in instrs {
-            match i.split_once(' ').unwrap() {
+            match source.split_once(i, " ")? {
                 ("$", "ls") => (),
-                ("$", "cd ..") => _ = f_tree.visit_stack.pop().unwrap(),
-                ("$", cd_dir) => f_tree
-                    .visit_stack
-                    .push(cd_dir.split_once(' ').unwrap().1.to_string()),
+                ("$", "cd ..") => {
+                    if f_tree.visit_stack.pop().is_none() {
+                        return Err(source.error(i, "a directory to leave"));
+                    }
+                }
+                ("$", cmd) => match source.split_once(cmd, " ")? {
+                    ("cd", cd_dir) => f_tree.visit_stack.push(cd_dir.to_string()),
+                    _ => return Err(source.error(cmd, "ls or cd")),
+                },
                 ("dir", dir) => {
                     let current_dir = f_tree.visit_stack.join("");
                     let sub_dir = [&current_dir, dir].join("");
@@ -42,12 +61,12 @@ impl FileTree {
                         .contents
                         .entry(current_dir)
                         .or_default()
-                        .push(filesize.parse().unwrap());
+                        .push(source.parse(filesize, "a file size, dir or $")?);
                 }
             }
         }
 
-        f_tree
+        Ok(f_tree)
     }
 }
 
@@ -77,8 +96,20 @@ fn get_sizes(f_tree: &FileTree, dir: String) -> HashMap<String, usize> {
 
 impl SolutionSimultaneous<FileTree, usize, usize> for Day7Solution {
     fn load(input: &str) -> Result<FileTree> {
-        let is = input.lines().skip(1).map(|s| s.to_string());
-        Ok(FileTree::from(is))
+        Self::load_with(input, &Params::default())
+    }
+
+    fn params() -> Vec<ParamInfo> {
+        vec![DISK_SIZE.info(), SPACE_NEEDED.info()]
+    }
+
+    fn load_with(input: &str, params: &Params) -> Result<FileTree> {
+        let is = input.lines().skip(1);
+        Ok(FileTree {
+            disk_size: DISK_SIZE.get(params)?,
+            space_needed: SPACE_NEEDED.get(params)?,
+            ..FileTree::from(Source(input), is)?
+        })
     }
 
     fn solve(input: FileTree) -> Result<(usize, usize)> {
@@ -88,7 +119,11 @@ impl SolutionSimultaneous<FileTree, usize, usize> for Day7Solution {
         let p1 = sizes.values().filter(|s| **s <= 100_000).sum();
 
         // calculate the amount of space we need to free
-        let target = 30_000_000 - (70_000_000 - sizes.get("/").unwrap());
+        let free = input
+            .disk_size
+            .checked_sub(*sizes.get("/").unwrap())
+            .ok_or_else(|| anyhow!("The files don't fit on a disk of size {}", input.disk_size))?;
+        let target = input.space_needed.saturating_sub(free);
 
         // find the smallest directory we can delete to meet the above target
         let p2 = sizes
@@ -96,57 +131,18 @@ impl SolutionSimultaneous<FileTree, usize, usize> for Day7Solution {
             .filter(|(_, size)| **size >= target)
             .sorted_by(|a, b| a.1.cmp(b.1))
             .next()
-            .unwrap()
+            .ok_or_else(|| anyhow!("No directory is big enough to free {} more space", target))?
             .1;
 
         Ok((p1, *p2))
     }
 }
 
-pub fn day07(input: &str) -> Result<f32> {
-    solve_simultaneous::<Day7Solution, _, _, _>(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::Day7Solution;
-    use crate::utils::solver_types::SolutionSimultaneous;
-
-    #[test]
-    fn test_answer() {
-        // note: ugly format so we don't lead with a \n
-        let input = r#"$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k"#;
-
-        let p1_expected = 95437;
-        let p2_expected = 24933642;
-
-        let loaded = Day7Solution::load(input).unwrap();
-
-        let (p1, p2) = Day7Solution::solve(loaded).unwrap();
-
-        assert_eq!(p1_expected, p1);
-        assert_eq!(p2_expected, p2);
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 7,
+        title: "No Space Left On Device",
+        solution: &simultaneous::<Day7Solution, _, _, _>(),
     }
 }
@@ -1,10 +1,12 @@
 use std::collections::HashSet;
 
+use crate::registry::Registration;
 use crate::utils::{
-    grid::Grid,
+    dense_grid::DenseGrid,
+    grid::GridLike,
     load_input::load_2d_grid,
     point::Pt,
-    solver_types::{solve_linear, SolutionLinear},
+    solver_types::{linear, SolutionLinear},
 };
 use anyhow::Result;
 use itertools::Itertools;
@@ -12,7 +14,7 @@ use itertools::Itertools;
 //not yet implemented
 pub struct Day8Solution {}
 
-type TreeGr = Grid<u8, 2>;
+type TreeGr = DenseGrid<u8, 2>;
 
 fn visible_from(grid: &TreeGr, start: Pt<2>, dir: Pt<2>, steps: isize) -> Vec<Pt<2>> {
     let mut last_highest = grid.get_def(&start);
@@ -51,7 +53,9 @@ fn get_trees_in_dir(grid: &TreeGr, start: Pt<2>, dir: Pt<2>, steps: isize) -> us
 
 impl SolutionLinear<TreeGr, usize, usize> for Day8Solution {
     fn load(input: &str) -> Result<TreeGr> {
-        Ok(load_2d_grid(input, |v| v.to_digit(10).unwrap() as u8))
+        Ok(load_2d_grid(input, "a tree height (0-9)", |v| {
+            v.to_digit(10).map(|d| d as u8)
+        })?)
     }
 
     fn part1(input: &mut TreeGr) -> Result<usize> {
@@ -92,34 +96,11 @@ impl SolutionLinear<TreeGr, usize, usize> for Day8Solution {
     }
 }
 
-pub fn day08(input: &str) -> Result<f32> {
-    solve_linear::<Day8Solution, _, _, _>(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::Day8Solution;
-    use crate::utils::solver_types::SolutionLinear;
-
-    #[test]
-    fn test_answer() {
-        // note: ugly format so we don't lead with a \n
-        let input = r#"30373
-25512
-65332
-33549
-35390"#;
-
-        let p1_expected = 21;
-        let p2_expected = 8;
-
-        let mut loaded = Day8Solution::load(input).unwrap();
-
-        let p1 = Day8Solution::part1(&mut loaded).unwrap();
-        assert_eq!(p1_expected, p1);
-
-        let p2 = Day8Solution::part2(&mut loaded, p1).unwrap();
-
-        assert_eq!(p2_expected, p2);
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 8,
+        title: "Treetop Tree House",
+        solution: &linear::<Day8Solution, _, _, _>(),
     }
 }
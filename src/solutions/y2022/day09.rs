@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use crate::registry::Registration;
+use crate::utils::{
+    params::{Param, ParamInfo, Params},
+    parse::{ParseError, Source},
+    point::Pt,
+    solver_types::{linear, SolutionLinear},
+};
+use anyhow::{anyhow, Result};
+pub struct Day9Solution {}
+
+/// how many knots are in part 2's rope, including the head
+const KNOTS: Param<usize> = Param::new("knots", 10);
+
+type Instr = (Pt<2>, usize); //direction, repeats
+
+struct Motions {
+    instrs: Vec<Instr>,
+    knots: usize,
+}
+
+fn to_instr(source: Source, line: &str) -> Result<Instr, ParseError> {
+    let (dir, reps) = source.split_once(line, " ")?;
+
+    let dir_pt = match dir {
+        "U" => Pt([0, -1]),
+        "D" => Pt([0, 1]),
+        "L" => Pt([-1, 0]),
+        "R" => Pt([1, 0]),
+        _ => return Err(source.error(dir, "U, D, L or R")),
+    };
+    Ok((dir_pt, source.parse(reps, "a number of steps")?))
+}
+
+/// return the position tail would have to go to 'catch up' to head.
+fn catch_up(tail: &Pt<2>, head: &Pt<2>) -> Pt<2> {
+    let Pt([x, y]) = head - tail;
+
+    match (x.abs(), y.abs()) {
+        (0, 0) | (1, 1) | (1, 0) | (0, 1) => *tail,
+        (a, b) if a > b => *head - Pt([x.signum(), 0]),
+        (a, b) if a == b => *head - Pt([x.signum(), y.signum()]),
+        _ => *head - Pt([0, y.signum()]),
+    }
+}
+
+impl SolutionLinear<Motions, usize, usize> for Day9Solution {
+    fn load(input: &str) -> Result<Motions> {
+        Self::load_with(input, &Params::default())
+    }
+
+    fn params() -> Vec<ParamInfo> {
+        vec![KNOTS.info()]
+    }
+
+    fn load_with(input: &str, params: &Params) -> Result<Motions> {
+        let knots = KNOTS.get(params)?;
+        if knots == 0 {
+            return Err(anyhow!("The rope needs at least one knot"));
+        }
+
+        Ok(Motions {
+            instrs: input
+                .lines()
+                .map(|l| to_instr(Source(input), l))
+                .collect::<Result<_, _>>()?,
+            knots,
+        })
+    }
+
+    fn part1(input: &mut Motions) -> Result<usize> {
+        let mut head = Pt::<2>::default();
+        let mut tail = Pt::<2>::default();
+        let mut tail_visited = HashSet::new();
+
+        for (dir, reps) in input.instrs.iter() {
+            for _ in 0..*reps {
+                head += *dir;
+                tail = catch_up(&tail, &head);
+                tail_visited.insert(tail);
+            }
+        }
+        Ok(tail_visited.len())
+    }
+
+    fn part2(input: &mut Motions, _part_1_solution: usize) -> Result<usize> {
+        let mut rope = vec![Pt::<2>::default(); input.knots];
+
+        let mut tail_visited = HashSet::new();
+
+        for (dir, reps) in input.instrs.iter() {
+            for _ in 0..*reps {
+                rope[0] += *dir;
+                for seg in 1..input.knots {
+                    let move_to = catch_up(&rope[seg], &rope[seg - 1]);
+
+                    rope[seg] = move_to;
+                }
+                tail_visited.insert(rope[input.knots - 1]);
+            }
+        }
+
+        Ok(tail_visited.len())
+    }
+}
+
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 9,
+        title: "Rope Bridge",
+        solution: &linear::<Day9Solution, _, _, _>(),
+    }
+}
@@ -0,0 +1,91 @@
+use crate::registry::Registration;
+use crate::utils::{
+    grid::{Grid, GridLike},
+    parse::{ParseError, Source},
+    solver_types::{linear, SolutionLinear},
+};
+use anyhow::Result;
+use itertools::Itertools;
+
+//not yet implemented
+pub struct Day10Solution {}
+
+fn execute_record(input: &[isize]) -> isize {
+    let mut reg = 1;
+
+    let mut next_read = 19;
+    let mut signal = 0;
+
+    for (i, add) in input.iter().enumerate() {
+        match i {
+            220 => break,
+            v if v == next_read => {
+                signal += (1 + next_read) as isize * reg; // lmao off by ones
+                next_read += 40;
+            }
+            _ => (),
+        }
+        reg += add;
+    }
+
+    signal
+}
+
+fn draw(input: &[isize]) -> Vec<Vec<isize>> {
+    let mut reg = 1;
+
+    let mut drawn = vec![];
+
+    for (i, add) in (0..241).zip(input.iter()) {
+        let cursor = i % 40;
+
+        let distance: isize = cursor - reg;
+        if distance.abs() < 2 {
+            drawn.push(vec![cursor, i / 40])
+        }
+
+        reg += add;
+    }
+
+    drawn
+}
+
+impl SolutionLinear<Vec<isize>, isize, String> for Day10Solution {
+    fn load(input: &str) -> Result<Vec<isize>> {
+        let source = Source(input);
+
+        Ok(input
+            .lines()
+            .map(|l| match l.split_once(' ') {
+                None if l == "noop" => Ok(vec![0]),
+                Some(("addx", v)) => {
+                    let v = source.parse::<isize>(v, "a number to add")?;
+                    Ok(vec![0, v])
+                }
+                _ => Err(source.error(l, "noop or addx")),
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?
+            .concat())
+    }
+
+    fn part1(input: &mut Vec<isize>) -> Result<isize> {
+        Ok(execute_record(input))
+    }
+
+    fn part2(input: &mut Vec<isize>, _part_1_solution: isize) -> Result<String> {
+        let to_draw = draw(input);
+
+        let g = Grid::<bool, 2>::from(to_draw.into_iter().map(|p| (p, true)).collect_vec());
+
+        Ok(g.print(|c| if c { '█' } else { ' ' }))
+    }
+}
+
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 10,
+        title: "Cathode-Ray Tube",
+        solution: &linear::<Day10Solution, _, _, _>(),
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::VecDeque;
+
+use crate::registry::Registration;
+use crate::utils::{
+    load_input::load_blocks,
+    params::{Param, ParamInfo, Params},
+    parse::Source,
+    solver_types::{linear, SolutionLinear},
+};
+use anyhow::Result;
+pub struct Day11Solution {}
+
+const PART_1_ROUNDS: Param<usize> = Param::new("part_1_rounds", 20);
+const PART_2_ROUNDS: Param<usize> = Param::new("part_2_rounds", 10000);
+
+#[derive(Default, Debug, Clone)]
+enum Op {
+    #[default]
+    MulSelf,
+    AddSelf,
+    Mul(usize),
+    Add(usize),
+}
+#[derive(Default, Debug, Clone)]
+struct Monkeys {
+    pub inventories: Vec<VecDeque<usize>>,
+    pub operations: Vec<Op>,
+    pub tests: Vec<(usize, usize, usize)>,
+    pub inspections: Vec<usize>,
+    pub rounds: (usize, usize),
+}
+
+fn do_test((div, a, b): &(usize, usize, usize), item: &usize) -> usize {
+    if item.is_multiple_of(*div) {
+        *a
+    } else {
+        *b
+    }
+}
+
+fn apply_monkey(
+    input: usize,
+    op: &Op,
+    test: &(usize, usize, usize),
+    lowest_common_monkeple: Option<usize>,
+) -> (usize, usize) {
+    let new_value = match op {
+        Op::MulSelf => input * input,
+        Op::AddSelf => input + input,
+        Op::Mul(v) => input * v,
+        Op::Add(v) => input + v,
+    };
+
+    let div_val = match lowest_common_monkeple {
+        None => new_value / 3,
+        Some(v) => new_value % v,
+    };
+
+    let new_owner = do_test(test, &div_val);
+
+    (new_owner, div_val)
+}
+fn do_round(monkeys: &mut Monkeys, lowest_common_monkeple: Option<usize>) {
+    for i in 0..monkeys.inventories.len() {
+        while !monkeys.inventories.get(i).unwrap().is_empty() {
+            let item = monkeys.inventories.get_mut(i).unwrap().pop_front().unwrap();
+            let (new_owner, new_value) = apply_monkey(
+                item,
+                monkeys.operations.get(i).unwrap(),
+                monkeys.tests.get(i).unwrap(),
+                lowest_common_monkeple,
+            );
+            monkeys
+                .inventories
+                .get_mut(new_owner)
+                .unwrap()
+                .push_back(new_value);
+
+            *monkeys.inspections.get_mut(i).unwrap() += 1;
+        }
+    }
+}
+
+impl SolutionLinear<Monkeys, usize, usize> for Day11Solution {
+    fn load(input: &str) -> Result<Monkeys> {
+        Self::load_with(input, &Params::default())
+    }
+
+    fn params() -> Vec<ParamInfo> {
+        vec![PART_1_ROUNDS.info(), PART_2_ROUNDS.info()]
+    }
+
+    fn load_with(input: &str, params: &Params) -> Result<Monkeys> {
+        let monkeys = load_blocks(input);
+
+        let mut result = Monkeys {
+            rounds: (PART_1_ROUNDS.get(params)?, PART_2_ROUNDS.get(params)?),
+            ..Default::default()
+        };
+
+        let source = Source(input);
+        fn last_word(l: &str) -> &str {
+            l.rsplit_once(' ').map_or(l, |(_, word)| word)
+        }
+
+        // the text of each monkey's throw targets, to check once every monkey is loaded
+        let mut targets = vec![];
+
+        for m in monkeys {
+            let ls: [&str; 6] = match m.lines().collect::<Vec<_>>() {
+                ls if ls.len() > 6 => {
+                    return Err(source.error(ls[6], "a blank line between monkeys").into())
+                }
+                ls => ls
+                    .try_into()
+                    .map_err(|_| source.error_after(m, "6 lines describing a monkey"))?,
+            };
+            // get inv
+            let inv = source
+                .split_once(ls[1], ": ")?
+                .1
+                .split(", ")
+                .map(|v| source.parse::<usize>(v, "a worry level"))
+                .collect::<Result<_, _>>()?;
+            result.inventories.push(inv);
+
+            // get op
+            let (op, val) = source.split_once(source.split_once(ls[2], "old ")?.1, " ")?;
+            let operation = match (op, val) {
+                ("+", "old") => Op::AddSelf,
+                ("*", "old") => Op::MulSelf,
+                ("+", val) => Op::Add(source.parse(val, "a number or old")?),
+                ("*", val) => Op::Mul(source.parse(val, "a number or old")?),
+                _ => return Err(source.error(op, "+ or *").into()),
+            };
+            result.operations.push(operation);
+
+            // get test
+            let divisor = last_word(ls[3]);
+            let test = (
+                source
+                    .parse(divisor, "a number to divide by")
+                    .and_then(|d| match d {
+                        0 => Err(source.error(divisor, "a non-zero number to divide by")),
+                        d => Ok(d),
+                    })?,
+                source.parse(last_word(ls[4]), "a monkey to throw to")?,
+                source.parse(last_word(ls[5]), "a monkey to throw to")?,
+            );
+            result.tests.push(test);
+            targets.push([last_word(ls[4]), last_word(ls[5])]);
+
+            result.inspections.push(0);
+        }
+
+        // only now do we know how many monkeys there are to throw to
+        for (monkey, (_, a, b)) in result.tests.iter().enumerate() {
+            for (target, text) in [a, b].into_iter().zip(targets[monkey]) {
+                if *target >= result.tests.len() || *target == monkey {
+                    return Err(source
+                        .error(
+                            text,
+                            format!(
+                                "another monkey to throw to, from 0 to {}",
+                                result.tests.len() - 1
+                            ),
+                        )
+                        .into());
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    fn part1(input: &mut Monkeys) -> Result<usize> {
+        let mut monkeys = input.clone();
+        for _ in 0..monkeys.rounds.0 {
+            do_round(&mut monkeys, None);
+        }
+
+        monkeys.inspections.sort();
+        Ok(monkeys.inspections.pop().unwrap() * monkeys.inspections.pop().unwrap())
+    }
+
+    fn part2(input: &mut Monkeys, _part_1_solution: usize) -> Result<usize> {
+        // find lowest common monkeple
+        let lcm: usize = input.tests.iter().map(|t| t.0).product();
+
+        for _ in 0..input.rounds.1 {
+            do_round(input, Some(lcm));
+        }
+
+        let mut results = input.inspections.clone();
+        results.sort();
+        Ok(results.pop().unwrap() * results.pop().unwrap())
+    }
+}
+
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 11,
+        title: "Monkey in the Middle",
+        solution: &linear::<Day11Solution, _, _, _>(),
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use crate::registry::Registration;
+use crate::utils::{
+    dense_grid::DenseGrid,
+    grid::GridLike,
+    load_input::load_2d_grid,
+    parse::Source,
+    point::Pt,
+    search::bfs,
+    solver_types::{simultaneous, SolutionSimultaneous},
+};
+use anyhow::{anyhow, Result};
+
+//not yet implemented
+pub struct Day12Solution {}
+
+#[derive(Default, Debug)]
+struct HeightGraph {
+    edges: HashMap<Pt<2>, Vec<Pt<2>>>,
+    start: Pt<2>,
+    end: Pt<2>,
+    all_starts: Vec<Pt<2>>,
+}
+
+impl SolutionSimultaneous<HeightGraph, usize, usize> for Day12Solution {
+    fn load(input: &str) -> Result<HeightGraph> {
+        let grid: DenseGrid<u8, 2> = load_2d_grid(input, "a height (a-z), S or E", |v| match v {
+            'S' => Some(0),
+            'E' => Some(27),
+            'a'..='z' => Some(v as u8 - 96),
+            _ => None,
+        })?;
+
+        // there must be exactly one start and one end
+        let source = Source(input);
+        for (marker, name) in [('S', "start"), ('E', "end")] {
+            let mut found = input.match_indices(marker).map(|(i, _)| &input[i..]);
+
+            if found.next().is_none() {
+                return Err(source
+                    .error_after(input.trim_end(), format!("the {}, marked {}", name, marker))
+                    .into());
+            }
+            if let Some(extra) = found.next() {
+                return Err(source
+                    .error(extra, format!("only one {}, marked {}", name, marker))
+                    .into());
+            }
+        }
+
+        // form graph
+        let mut graph = HeightGraph::default();
+
+        for (pt, v) in grid.iter() {
+            let mut v = *v;
+            if v == 1 {
+                graph.all_starts.push(pt);
+            }
+            if v == 0 {
+                graph.start = pt;
+                v += 1;
+            }
+            if v == 27 {
+                graph.end = pt;
+                v -= 1;
+            }
+            for (neighbour, height) in grid.card_neighbours(&pt) {
+                if *height >= v - 1 {
+                    graph.edges.entry(pt).or_default().push(neighbour);
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
+    fn solve(input: HeightGraph) -> Result<(usize, usize)> {
+        // search backwards from the end, so one search finds the distance from every start
+        let reached = bfs(input.end, |pt| {
+            input.edges.get(pt).into_iter().flatten().copied()
+        });
+
+        Ok((
+            reached
+                .distance(&input.start)
+                .ok_or_else(|| anyhow!("The end can't be reached from the start"))?,
+            input
+                .all_starts
+                .iter()
+                .filter_map(|a| reached.distance(a))
+                .min()
+                .unwrap_or(usize::MAX),
+        ))
+    }
+}
+
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 12,
+        title: "Hill Climbing Algorithm",
+        solution: &simultaneous::<Day12Solution, _, _, _>(),
+    }
+}
@@ -1,6 +1,11 @@
-use std::{cmp::Ordering, iter::Peekable, str::Chars};
-
-use crate::utils::solver_types::{solve_linear, SolutionLinear};
+use std::{cmp::Ordering, iter::Peekable, str::CharIndices};
+
+use crate::registry::Registration;
+use crate::utils::{
+    load_input::load_blocks,
+    parse::{ParseError, Source},
+    solver_types::{linear, SolutionLinear},
+};
 use anyhow::Result;
 use itertools::{EitherOrBoth, Itertools};
 
@@ -39,45 +44,59 @@ impl Pa {
     }
 }
 
-fn to_pa(packet: &mut Peekable<Chars>) -> Vec<Pa> {
+fn to_pa(
+    source: Source,
+    line: &str,
+    packet: &mut Peekable<CharIndices>,
+) -> Result<Vec<Pa>, ParseError> {
     let mut result = vec![];
 
-    while packet.peek().is_some() {
-        match packet.next().unwrap() {
-            ',' => (),
-            '[' => result.push(Pa::List(to_pa(packet))),
-            ']' => break,
-            val => {
-                let next = packet.peek().and_then(|v| v.to_digit(10));
-
-                match next {
-                    None => result.push(Pa::Num(val.to_digit(10).unwrap())),
-                    _ => result.push(Pa::Num(
-                        format!("{}{}", val, packet.next().unwrap())
-                            .parse()
-                            .unwrap(),
-                    )),
+    loop {
+        match packet.next() {
+            None => return Err(source.error_after(line, "']'")),
+            Some((_, ',')) => (),
+            Some((_, '[')) => result.push(Pa::List(to_pa(source, line, packet)?)),
+            Some((_, ']')) => break,
+            Some((start, c)) if c.is_ascii_digit() => {
+                let mut end = start + 1;
+                while let Some((i, _)) = packet.next_if(|(_, c)| c.is_ascii_digit()) {
+                    end = i + 1;
                 }
+                result.push(Pa::Num(source.parse(&line[start..end], "a number")?));
             }
+            Some((i, _)) => return Err(source.error(&line[i..], "a number, ',', '[' or ']'")),
         }
     }
 
-    result
+    Ok(result)
+}
+
+/// parse a whole packet, which is a single list
+fn to_packet(source: Source, line: &str) -> Result<Pa, ParseError> {
+    let mut chars = line.char_indices().peekable();
+    if chars.next().map(|(_, c)| c) != Some('[') {
+        return Err(source.error(line, "'['"));
+    }
+
+    let packet = Pa::List(to_pa(source, line, &mut chars)?);
+
+    match chars.next() {
+        None => Ok(packet),
+        Some((i, _)) => Err(source.error(&line[i..], "the end of the packet")),
+    }
 }
 
 impl SolutionLinear<Vec<(Pa, Pa)>, usize, usize> for Day13Solution {
     fn load(input: &str) -> Result<Vec<(Pa, Pa)>> {
-        Ok(input
-            .split("\n\n")
+        let source = Source(input);
+
+        Ok(load_blocks(input)
             .map(|pair| {
-                let (l, r) = pair.split_once('\n').unwrap();
+                let (l, r) = source.split_once(pair.trim_end(), "\n")?;
 
-                (
-                    Pa::List(to_pa(&mut l.chars().peekable())),
-                    Pa::List(to_pa(&mut r.chars().peekable())),
-                )
+                Ok((to_packet(source, l.trim_end())?, to_packet(source, r)?))
             })
-            .collect())
+            .collect::<Result<_, ParseError>>()?)
     }
 
     fn part1(input: &mut Vec<(Pa, Pa)>) -> Result<usize> {
@@ -123,52 +142,11 @@ impl SolutionLinear<Vec<(Pa, Pa)>, usize, usize> for Day13Solution {
     }
 }
 
-pub fn day13(input: &str) -> Result<f32> {
-    solve_linear::<Day13Solution, _, _, _>(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::Day13Solution;
-    use crate::utils::solver_types::SolutionLinear;
-
-    #[test]
-    fn test_answer() {
-        // note: ugly format so we don't lead with a \n
-        let input = r#"[1,1,3,1,1]
-[1,1,5,1,1]
-
-[[1],[2,3,4]]
-[[1],4]
-
-[9]
-[[8,7,6]]
-
-[[4,4],4,4]
-[[4,4],4,4,4]
-
-[7,7,7,7]
-[7,7,7]
-
-[]
-[3]
-
-[[[]]]
-[[]]
-
-[1,[2,[3,[4,[5,6,7]]]],8,9]
-[1,[2,[3,[4,[5,6,0]]]],8,9]"#;
-
-        let p1_expected = 13;
-        let p2_expected = 140;
-
-        let mut loaded = Day13Solution::load(input).unwrap();
-
-        let p1 = Day13Solution::part1(&mut loaded).unwrap();
-        assert_eq!(p1_expected, p1);
-
-        let p2 = Day13Solution::part2(&mut loaded, p1).unwrap();
-
-        assert_eq!(p2_expected, p2);
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 13,
+        title: "Distress Signal",
+        solution: &linear::<Day13Solution, _, _, _>(),
     }
 }
@@ -1,10 +1,11 @@
+use crate::registry::Registration;
 use crate::utils::{
-    grid::Grid,
+    grid::{Grid, GridLike},
+    parse::{ParseError, Source},
     point::Pt,
-    solver_types::{solve_linear, SolutionLinear},
+    solver_types::{linear, SolutionLinear},
 };
 use anyhow::Result;
-use itertools::Itertools;
 
 //not yet implemented
 pub struct Day14Solution {}
@@ -60,36 +61,41 @@ fn do_sand(
 
 impl SolutionLinear<Grid<Space, 2>, usize, usize> for Day14Solution {
     fn load(input: &str) -> Result<Grid<Space, 2>> {
-        let rocks = input
-            .lines()
-            .flat_map(|l| {
-                let mut ends = l.split(" -> ").map(|pos| {
-                    let (x, y) = pos.split_once(',').unwrap();
-                    Pt([x.parse().unwrap(), y.parse().unwrap()])
-                });
-
-                let mut last = ends.next().unwrap();
-                let mut pts = vec![];
-
-                for Pt([x, y]) in ends {
-                    if x != last.0[0] {
-                        let mut xs = [x, last.0[0]];
-                        xs.sort();
-                        for xx in xs[0]..xs[1] + 1 {
-                            pts.push(Pt([xx, y]));
-                        }
-                    } else {
-                        let mut ys = [y, last.0[1]];
-                        ys.sort();
-                        for yy in ys[0]..ys[1] + 1 {
-                            pts.push(Pt([x, yy]));
-                        }
+        let source = Source(input);
+        let mut rocks = vec![];
+
+        for l in input.lines() {
+            let mut ends = l.split(" -> ").map(|pos| {
+                let (x, y) = source.split_once(pos, ",")?;
+                Ok::<_, ParseError>(Pt([
+                    source.parse(x, "an x co-ordinate")?,
+                    source.parse(y, "a y co-ordinate")?,
+                ]))
+            });
+
+            let mut last = match ends.next() {
+                Some(end) => end?,
+                None => continue,
+            };
+
+            for end in ends {
+                let Pt([x, y]) = end?;
+                if x != last.0[0] {
+                    let mut xs = [x, last.0[0]];
+                    xs.sort();
+                    for xx in xs[0]..xs[1] + 1 {
+                        rocks.push((Pt([xx, y]), Space::Rock));
+                    }
+                } else {
+                    let mut ys = [y, last.0[1]];
+                    ys.sort();
+                    for yy in ys[0]..ys[1] + 1 {
+                        rocks.push((Pt([x, yy]), Space::Rock));
                     }
-                    last = Pt([x, y])
                 }
-                pts.into_iter().map(|p| (p, Space::Rock))
-            })
-            .collect_vec();
+                last = Pt([x, y])
+            }
+        }
 
         Ok(Grid::from(rocks))
     }
@@ -128,10 +134,6 @@ impl SolutionLinear<Grid<Space, 2>, usize, usize> for Day14Solution {
     }
 }
 
-pub fn day14(input: &str) -> Result<f32> {
-    solve_linear::<Day14Solution, _, _, _>(input)
-}
-
 #[allow(dead_code)]
 fn print_maze(grid: &Grid<Space, 2>) {
     println!(
@@ -144,26 +146,11 @@ fn print_maze(grid: &Grid<Space, 2>) {
     );
 }
 
-#[cfg(test)]
-mod tests {
-    use super::Day14Solution;
-    use crate::utils::solver_types::SolutionLinear;
-
-    #[test]
-    fn test_answer() {
-        // note: ugly format so we don't lead with a \n
-        let input = r#"498,4 -> 498,6 -> 496,6
-503,4 -> 502,4 -> 502,9 -> 494,9"#;
-
-        let p1_expected = 24;
-        let p2_expected = 93;
-
-        let mut loaded = Day14Solution::load(input).unwrap();
-
-        let p1 = Day14Solution::part1(&mut loaded).unwrap();
-        assert_eq!(p1_expected, p1);
-        let p2 = Day14Solution::part2(&mut loaded, p1).unwrap();
-
-        assert_eq!(p2_expected, p2);
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 14,
+        title: "Regolith Reservoir",
+        solution: &linear::<Day14Solution, _, _, _>(),
     }
 }
@@ -0,0 +1,140 @@
+use std::{
+    cmp::max,
+    collections::{HashMap, HashSet},
+};
+
+use crate::registry::Registration;
+use crate::utils::{
+    params::{Param, ParamInfo, Params},
+    parse::{ParseError, Source},
+    point::Pt,
+    solver_types::{linear, SolutionLinear},
+};
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(
+        r"Sensor at x=([-\d]+), y=([-\d]+): closest beacon is at x=([-\d]+), y=([-\d]+)"
+    )
+    .unwrap();
+}
+
+pub struct Day15Solution {}
+
+/// the row to check in part 1. Part 2 searches x and y between 0 and twice this
+const TARGET: Param<isize> = Param::new("target", 2_000_000);
+
+struct Input {
+    target: isize,
+    beacons: HashSet<Pt<2>>,
+    covered: HashMap<isize, Vec<(isize, isize)>>, // y co-ord -> bounds covered by beacons
+}
+
+fn manhattan(Pt([x1, y1]): &Pt<2>, Pt([x2, y2]): &Pt<2>) -> isize {
+    (x1 - x2).abs() + (y1 - y2).abs()
+}
+
+impl SolutionLinear<Input, usize, isize> for Day15Solution {
+    fn load(input: &str) -> Result<Input> {
+        Self::load_with(input, &Params::default())
+    }
+
+    fn params() -> Vec<ParamInfo> {
+        vec![TARGET.info()]
+    }
+
+    fn load_with(input: &str, params: &Params) -> Result<Input> {
+        let source = Source(input);
+        let sen_beacons = input
+            .lines()
+            .map(|l| {
+                let [x1, y1, x2, y2] = source.groups(l, &RE)?;
+                let coord = |c| source.parse(c, "a co-ordinate");
+
+                Ok((Pt([coord(x1)?, coord(y1)?]), Pt([coord(x2)?, coord(y2)?])))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        let beacons = sen_beacons.iter().map(|(_, pt)| *pt).collect();
+
+        let mut covered: HashMap<isize, Vec<(isize, isize)>> = HashMap::new();
+
+        for (sen, beacon) in sen_beacons.iter() {
+            let distance = manhattan(sen, beacon);
+            for y in sen.0[1] - distance..sen.0[1] + distance + 1 {
+                let y_distance = (sen.0[1] - y).abs();
+
+                covered.entry(y).or_default().push((
+                    // x-left
+                    sen.0[0] - (distance - y_distance),
+                    // x-right
+                    sen.0[0] + (distance - y_distance),
+                ))
+            }
+        }
+
+        for (_, row) in covered.iter_mut() {
+            row.sort()
+        }
+
+        Ok(Input {
+            target: TARGET.get(params)?,
+            beacons,
+            covered,
+        })
+    }
+
+    fn part1(input: &mut Input) -> Result<usize> {
+        // get contigous area covered on the target row
+        let mut target_row = HashSet::new();
+        let covered = input
+            .covered
+            .get(&input.target)
+            .ok_or_else(|| anyhow!("No sensor covers row {}", input.target))?;
+
+        for (a, b) in covered {
+            for x in *a..*b + 1 {
+                target_row.insert(Pt([x, input.target]));
+            }
+        }
+
+        Ok(target_row.difference(&input.beacons).count())
+    }
+
+    fn part2(input: &mut Input, _part_1_solution: usize) -> Result<isize> {
+        // for each row, find the first x not covered between 0 and target*2
+        let limit = input.target * 2;
+        for y in 0..=limit {
+            let covered = input
+                .covered
+                .get(&y)
+                .ok_or_else(|| anyhow!("No sensor covers row {}", y))?;
+
+            // the first x that might not be covered
+            let mut next = 0;
+            for (a, b) in covered {
+                if next < *a {
+                    break;
+                }
+                next = max(next, b + 1);
+            }
+
+            if next <= limit {
+                return Ok(next * 4_000_000 + y);
+            }
+        }
+
+        Err(anyhow!("No gap found in rows 0 to {}", limit))
+    }
+}
+
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 15,
+        title: "Beacon Exclusion Zone",
+        solution: &linear::<Day15Solution, _, _, _>(),
+    }
+}
@@ -1,9 +1,14 @@
-use crate::utils::solver_types::{solve_linear, SolutionLinear};
-use anyhow::Result;
+use crate::registry::Registration;
+use crate::utils::{
+    cancel::check_cancelled,
+    parse::Source,
+    search::bfs,
+    solver_types::{linear, SolutionLinear},
+};
+use anyhow::{anyhow, Result};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::ops::Deref;
 use std::{
     cmp::max,
     collections::{HashMap, HashSet},
@@ -17,23 +22,6 @@ lazy_static! {
 
 pub struct Day16Solution {}
 
-fn distance_between(graph: &HashMap<&str, Vec<&str>>, start: &str, end: &str) -> usize {
-    let mut to_visit = vec![start];
-    let mut distance = 0;
-
-    loop {
-        if to_visit.contains(&end) {
-            return distance;
-        }
-        let mut next: Vec<&str> = vec![];
-        for v in to_visit {
-            next.extend(graph.get(v).unwrap().iter().map(Deref::deref));
-        }
-        distance += 1;
-        to_visit = next;
-    }
-}
-
 fn dfs(input: &Input, start: usize, visited: HashSet<usize>, remaining: usize) -> usize {
     let mut max_seen = 0;
 
@@ -78,12 +66,13 @@ impl SolutionLinear<Input, usize, usize> for Day16Solution {
         let mut flow_rates = HashMap::from([(0, 0)]);
         let mut adjs = HashMap::new();
 
-        for l in input.lines() {
-            let ms = RE.captures(l).unwrap();
+        let source = Source(input);
+        let mut valves = vec![];
 
-            let node = ms.get(1).unwrap().as_str();
+        for l in input.lines() {
+            let [node, flow, tunnels] = source.groups(l, &RE)?;
 
-            let flow = ms.get(2).unwrap().as_str().parse::<usize>().unwrap();
+            let flow = source.parse::<usize>(flow, "a flow rate")?;
 
             if flow > 0 {
                 str_map.insert(node, important.len());
@@ -91,20 +80,39 @@ impl SolutionLinear<Input, usize, usize> for Day16Solution {
                 important.push(node);
             }
 
-            adjs.insert(node, ms.get(3).unwrap().as_str().split(", ").collect_vec());
+            adjs.insert(node, tunnels.split(", ").collect_vec());
+            valves.push(node);
+        }
+
+        // every tunnel has to lead somewhere, and we always start at AA
+        if !adjs.contains_key("AA") {
+            return Err(source.error_after(input, "a valve named AA").into());
+        }
+        if let Some(tunnel) = valves
+            .iter()
+            .flat_map(|v| &adjs[v])
+            .find(|t| !adjs.contains_key(*t))
+        {
+            return Err(source
+                .error(tunnel, "a tunnel leading to a valve in the input")
+                .into());
         }
 
         // generate matrix of distances
         let mut distances: Vec<Vec<usize>> = vec![vec![0; important.len()]; important.len()];
 
         for a in &important {
+            let reached = bfs(*a, |v| adjs[v].iter().copied());
+
             for b in &important {
                 if a == b {
                     continue;
                 }
                 let i_a = *str_map.get(a).unwrap();
                 let i_b = *str_map.get(b).unwrap();
-                let dist = distance_between(&adjs, a, b);
+                let dist = reached
+                    .distance(b)
+                    .ok_or_else(|| anyhow!("Valve {} can't be reached from {}", b, a))?;
                 *distances.get_mut(i_a).unwrap().index_mut(i_b) = dist;
             }
         }
@@ -136,6 +144,7 @@ impl SolutionLinear<Input, usize, usize> for Day16Solution {
             .collect();
 
         for p in powerset {
+            check_cancelled()?;
             seen.insert(to_signature(&p));
             let mut other = vec![0];
             other.extend(all.iter().filter(|v| !p.contains(v)));
@@ -155,38 +164,11 @@ impl SolutionLinear<Input, usize, usize> for Day16Solution {
     }
 }
 
-pub fn day16(input: &str) -> Result<f32> {
-    solve_linear::<Day16Solution, _, _, _>(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::Day16Solution;
-    use crate::utils::solver_types::SolutionLinear;
-
-    #[test]
-    fn test_answer() {
-        // note: ugly format so we don't lead with a \n
-        let input = r#"Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
-Valve BB has flow rate=13; tunnels lead to valves CC, AA
-Valve CC has flow rate=2; tunnels lead to valves DD, BB
-Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
-Valve EE has flow rate=3; tunnels lead to valves FF, DD
-Valve FF has flow rate=0; tunnels lead to valves EE, GG
-Valve GG has flow rate=0; tunnels lead to valves FF, HH
-Valve HH has flow rate=22; tunnel leads to valve GG
-Valve II has flow rate=0; tunnels lead to valves AA, JJ
-Valve JJ has flow rate=21; tunnel leads to valve II"#;
-
-        let p1_expected = 1651;
-        let p2_expected = 1707;
-
-        let mut loaded = Day16Solution::load(input).unwrap();
-        let p1 = Day16Solution::part1(&mut loaded).unwrap();
-        assert_eq!(p1_expected, p1);
-
-        let p2 = Day16Solution::part2(&mut loaded, p1).unwrap();
-
-        assert_eq!(p2_expected, p2);
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 16,
+        title: "Proboscidea Volcanium",
+        solution: &linear::<Day16Solution, _, _, _>(),
     }
 }
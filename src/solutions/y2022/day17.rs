@@ -1,12 +1,21 @@
 use std::collections::{HashMap, VecDeque};
 
-use crate::utils::solver_types::{solve_linear, SolutionLinear};
+use crate::registry::Registration;
+use crate::utils::{
+    cancel::check_cancelled,
+    params::{Param, ParamInfo, Params},
+    parse::Source,
+    solver_types::{linear, SolutionLinear},
+};
 use anyhow::Result;
 use itertools::Itertools;
 
 //not yet implemented
 pub struct Day17Solution {}
 
+const PART_1_ROCKS: Param<usize> = Param::new("part_1_rocks", 2022);
+const PART_2_ROCKS: Param<usize> = Param::new("part_2_rocks", 1_000_000_000_000);
+
 type Shape = [u8; 4];
 
 const FLAT: Shape = [0b0000_0000, 0b0000_0000, 0b0000_0000, 0b0001_1110];
@@ -107,109 +116,108 @@ fn print_rows(rows: &[u8]) {
     }
 }
 
-impl SolutionLinear<Vec<Move>, usize, usize> for Day17Solution {
-    fn load(input: &str) -> Result<Vec<Move>> {
-        Ok(input
-            .chars()
-            .map(|c| match c {
-                '<' => Move::Left,
-                '>' => Move::Right,
-                _ => unreachable!(),
-            })
-            .collect())
+/// the jet pattern, and how many rocks to drop for each part
+struct Jets {
+    moves: Vec<Move>,
+    rocks: (usize, usize),
+}
+
+impl SolutionLinear<Jets, usize, usize> for Day17Solution {
+    fn load(input: &str) -> Result<Jets> {
+        Self::load_with(input, &Params::default())
     }
 
-    fn part1(input: &mut Vec<Move>) -> Result<usize> {
-        let mut instrs = input.iter().enumerate().cycle().peekable();
+    fn params() -> Vec<ParamInfo> {
+        vec![PART_1_ROCKS.info(), PART_2_ROCKS.info()]
+    }
+
+    fn load_with(input: &str, params: &Params) -> Result<Jets> {
+        let source = Source(input);
+        let pattern = input.trim_end();
+        if pattern.is_empty() {
+            return Err(source.error(input, "a jet pattern").into());
+        }
+
+        Ok(Jets {
+            moves: pattern
+                .char_indices()
+                .map(|(i, c)| match c {
+                    '<' => Ok(Move::Left),
+                    '>' => Ok(Move::Right),
+                    _ => Err(source.error(&pattern[i..], "< or >")),
+                })
+                .collect::<Result<_, _>>()?,
+            rocks: (PART_1_ROCKS.get(params)?, PART_2_ROCKS.get(params)?),
+        })
+    }
+
+    fn part1(input: &mut Jets) -> Result<usize> {
+        let mut instrs = input.moves.iter().enumerate().cycle().peekable();
         let mut shapes = QUEUE.iter().enumerate().cycle();
         let mut stack = vec![0b1111_1111];
 
-        for _ in 0..2022 {
+        for _ in 0..input.rocks.0 {
             stack = fall_piece(&mut instrs, &mut shapes, stack);
         }
 
         Ok(stack.len() - 1) // -1 for 0th row
     }
 
-    fn part2(input: &mut Vec<Move>, _part_1_solution: usize) -> Result<usize> {
-        let mut instrs = input.iter().enumerate().cycle().peekable();
+    fn part2(input: &mut Jets, _part_1_solution: usize) -> Result<usize> {
+        let rocks = input.rocks.1;
+        let mut instrs = input.moves.iter().enumerate().cycle().peekable();
         let mut shapes = QUEUE.iter().enumerate().cycle().peekable();
         let mut stack = vec![0b1111_1111];
         let mut recent_history = VecDeque::new();
         let mut seen = HashMap::new();
+        let mut dropped = 0;
 
-        // at each step, record the 'fingerprint' of the state (the instruction, shape and top line state) along with how many steps into a cycle we are and the height.
-        // when the fingerprint is repeated, find the cycle length and height of the repeated section, and simulate skipping the repeated section by calculating the total
-        // height and cycle count.
+        // at each step, record the 'fingerprint' of the state (the instruction, shape and recent top lines) along with how many rocks have fallen and the height.
+        // when the fingerprint is repeated, find the length and height of the repeated section, and skip as many whole repeats as fit in the remaining rocks.
         // get the remainder manually.
-        for step in 1usize.. {
-            if recent_history.len() > 10 {
-                recent_history.pop_back();
-                recent_history.push_front(stack.iter().copied().last().unwrap())
+        loop {
+            check_cancelled()?;
+            if dropped == rocks {
+                return Ok(stack.len() - 1);
             }
 
-            if seen.contains_key(&(
+            let fingerprint = (
                 instrs.peek().unwrap().0,
                 shapes.peek().unwrap().0,
                 recent_history.clone(),
-            )) {
-                let (pre_cycle_step, pre_cycle_height) = seen
-                    .get(&(
-                        instrs.peek().unwrap().0,
-                        shapes.peek().unwrap().0,
-                        recent_history,
-                    ))
-                    .unwrap();
-
-                let cycle_length = step - pre_cycle_step;
+            );
+
+            if let Some((pre_cycle_dropped, pre_cycle_height)) = seen.get(&fingerprint) {
+                let cycle_length = dropped - pre_cycle_dropped;
                 let cycle_height = stack.len() - pre_cycle_height;
 
-                let repeats = 1_000_000_000_000 / cycle_length;
-                let height = cycle_height * repeats;
+                let remaining = rocks - dropped;
+                let skipped_height = cycle_height * (remaining / cycle_length);
 
-                for _ in 0..(1_000_000_000_000 % cycle_length) {
+                for _ in 0..(remaining % cycle_length) {
                     stack = fall_piece(&mut instrs, &mut shapes, stack);
                 }
 
-                return Ok(height + stack.len() - pre_cycle_height - cycle_height - 1);
+                return Ok(stack.len() - 1 + skipped_height); // -1 for 0th row
             }
 
-            seen.insert(
-                (
-                    instrs.peek().unwrap().0,
-                    shapes.peek().unwrap().0,
-                    recent_history.clone(),
-                ),
-                (step, stack.len()),
-            );
+            seen.insert(fingerprint, (dropped, stack.len()));
             stack = fall_piece(&mut instrs, &mut shapes, stack);
+
+            recent_history.push_front(*stack.last().unwrap());
+            if recent_history.len() > 10 {
+                recent_history.pop_back();
+            }
+            dropped += 1;
         }
-        unreachable!()
     }
 }
 
-pub fn day17(input: &str) -> Result<f32> {
-    solve_linear::<Day17Solution, _, _, _>(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::Day17Solution;
-    use crate::utils::solver_types::SolutionLinear;
-
-    #[test]
-    fn test_answer() {
-        let input = r#">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>"#;
-
-        let p1_expected = 3068;
-        let p2_expected = 1514285714288;
-
-        let mut loaded = Day17Solution::load(input).unwrap();
-        let p1 = Day17Solution::part1(&mut loaded).unwrap();
-        assert_eq!(p1_expected, p1);
-
-        let p2 = Day17Solution::part2(&mut loaded, p1).unwrap();
-
-        assert_eq!(p2_expected, p2);
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 17,
+        title: "Pyroclastic Flow",
+        solution: &linear::<Day17Solution, _, _, _>(),
     }
 }
@@ -0,0 +1,63 @@
+use crate::registry::Registration;
+use crate::utils::grid::{Grid, GridLike, Neighbourhood};
+use crate::utils::parse::{ParseError, Source};
+use crate::utils::point::Pt;
+use crate::utils::solver_types::{linear, SolutionLinear};
+use anyhow::Result;
+
+pub struct Day18Solution {}
+
+impl SolutionLinear<Grid<bool, 3>, usize, usize> for Day18Solution {
+    fn load(input: &str) -> Result<Grid<bool, 3>> {
+        let source = Source(input);
+
+        let mut grid = Grid::from(
+            input
+                .lines()
+                .map(|l| {
+                    let (x, yz) = source.split_once(l, ",")?;
+                    let (y, z) = source.split_once(yz, ",")?;
+                    let coord = |c| source.parse(c, "a co-ordinate");
+
+                    Ok((Pt([coord(x)?, coord(y)?, coord(z)?]), true))
+                })
+                .collect::<Result<Vec<_>, ParseError>>()?,
+        );
+        // steam can't move diagonally
+        grid.set_neighbourhood(Neighbourhood::VonNeumann);
+
+        Ok(grid)
+    }
+
+    fn part1(input: &mut Grid<bool, 3>) -> Result<usize> {
+        Ok(input
+            .grid
+            .keys()
+            .map(|coord| input.card_offsets.len() - input.card_neighbours(coord).len())
+            .sum())
+    }
+
+    fn part2(input: &mut Grid<bool, 3>, _part_1_solution: usize) -> Result<usize> {
+        // flood the air around the droplet, with a layer of padding so it can reach all the way round
+        let (mins, maxs) = input.bounds();
+        let bounds = (mins.map(|v| v - 1), maxs.map(|v| v + 1));
+        let outside = input.flood_fill(Pt(bounds.0), |lava| !lava, bounds);
+
+        // only faces touching the outside air count
+        Ok(input
+            .grid
+            .keys()
+            .flat_map(|coord| input.card_offsets.iter().map(move |off| coord + off))
+            .filter(|n| outside.contains(n))
+            .count())
+    }
+}
+
+inventory::submit! {
+    Registration {
+        year: 2022,
+        day: 18,
+        title: "Boiling Boulders",
+        solution: &linear::<Day18Solution, _, _, _>(),
+    }
+}
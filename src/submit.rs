@@ -0,0 +1,319 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::client::AocClient;
+
+/// Default location of the submission history
+pub const DEFAULT_HISTORY_PATH: &str = "./submissions.json";
+
+lazy_static! {
+    static ref ARTICLE: Regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+}
+
+/// What the server told us about a submitted answer
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// we submitted too soon after a previous wrong answer
+    RateLimited,
+    /// the part is already solved or not yet unlocked
+    WrongLevel,
+    Unknown,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Outcome::Correct => "CORRECT",
+            Outcome::Incorrect => "INCORRECT",
+            Outcome::TooHigh => "INCORRECT (too high)",
+            Outcome::TooLow => "INCORRECT (too low)",
+            Outcome::RateLimited => "RATE LIMITED",
+            Outcome::WrongLevel => "WRONG LEVEL",
+            Outcome::Unknown => "UNKNOWN RESPONSE",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl Outcome {
+    /// whether the server told us this answer is wrong
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow
+        )
+    }
+}
+
+/// Get the message from an answer response page, without any html
+pub fn response_message(page: &str) -> String {
+    let article = ARTICLE
+        .captures(page)
+        .and_then(|caps| caps.get(1))
+        .map_or(page, |m| m.as_str());
+
+    TAG.replace_all(article, "").trim().to_string()
+}
+
+/// Work out what the server said about our answer from its message
+pub fn classify(message: &str) -> Outcome {
+    match message {
+        m if m.contains("That's the right answer") => Outcome::Correct,
+        m if m.contains("your answer is too high") => Outcome::TooHigh,
+        m if m.contains("your answer is too low") => Outcome::TooLow,
+        m if m.contains("That's not the right answer") => Outcome::Incorrect,
+        m if m.contains("You gave an answer too recently") => Outcome::RateLimited,
+        m if m.contains("You don't seem to be solving the right level") => Outcome::WrongLevel,
+        _ => Outcome::Unknown,
+    }
+}
+
+/// A single answer we sent to the server, and what it said back
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub message: String,
+    /// seconds since the unix epoch
+    pub submitted_at: u64,
+}
+
+/// Why we won't submit an answer without asking first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// nothing is known about this answer
+    Ok,
+    /// this exact answer was already rejected, or the part is already solved
+    Refuse(String),
+    /// the answer is outside the range left by previous too high/too low answers
+    Warn(String),
+}
+
+/// Every answer we've submitted
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct History(pub Vec<Submission>);
+
+impl History {
+    /// Load the history file, or start a new history if it doesn't exist
+    pub fn load(file_path: &Path) -> Result<Self> {
+        if !file_path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = fs::read_to_string(file_path).map_err(|error| {
+            anyhow!(
+                "Failed to read submission history '{}'. Reason: {}",
+                file_path.display(),
+                error
+            )
+        })?;
+
+        serde_json::from_str(&text)
+            .map_err(|error| anyhow!("Invalid submission history. Reason: {}", error))
+    }
+
+    pub fn save(&self, file_path: &Path) -> Result<()> {
+        fs::write(file_path, serde_json::to_string_pretty(self)?).map_err(|error| {
+            anyhow!(
+                "Failed to write submission history '{}'. Reason: {}",
+                file_path.display(),
+                error
+            )
+        })
+    }
+
+    fn for_part(&self, year: usize, day: usize, part: usize) -> impl Iterator<Item = &Submission> {
+        self.0
+            .iter()
+            .filter(move |s| s.year == year && s.day == day && s.part == part)
+    }
+
+    /// Check an answer against everything we already know about this part
+    pub fn check(&self, year: usize, day: usize, part: usize, answer: &str) -> Check {
+        let previous = self.for_part(year, day, part).collect::<Vec<_>>();
+
+        if let Some(correct) = previous.iter().find(|s| s.outcome == Outcome::Correct) {
+            return Check::Refuse(format!(
+                "This part was already solved with answer '{}'",
+                correct.answer
+            ));
+        }
+        if let Some(wrong) = previous
+            .iter()
+            .find(|s| s.answer == answer && s.outcome.is_wrong())
+        {
+            return Check::Refuse(format!(
+                "'{}' was already submitted and was {}",
+                answer, wrong.outcome
+            ));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Check::Ok;
+        };
+        let bound = |outcome| {
+            previous
+                .iter()
+                .filter(move |s| s.outcome == outcome)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+
+        if let Some(too_high) = bound(Outcome::TooHigh).min().filter(|h| value >= *h) {
+            return Check::Warn(format!(
+                "{} is at least {}, which was already too high",
+                value, too_high
+            ));
+        }
+        if let Some(too_low) = bound(Outcome::TooLow).max().filter(|l| value <= *l) {
+            return Check::Warn(format!(
+                "{} is at most {}, which was already too low",
+                value, too_low
+            ));
+        }
+
+        Check::Ok
+    }
+}
+
+/// Submit an answer and record what the server said in the history
+pub fn submit(
+    client: &AocClient,
+    history: &mut History,
+    year: usize,
+    day: usize,
+    part: usize,
+    answer: &str,
+) -> Result<Submission> {
+    let page = client.submit_answer(year, day, part, answer)?;
+    let message = response_message(&page);
+
+    let submission = Submission {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome: classify(&message),
+        message,
+        submitted_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+    };
+
+    history.0.push(submission.clone());
+
+    Ok(submission)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify, response_message, submit, Check, History, Outcome, Submission};
+    use crate::client::{tests::stub_server, AocClient};
+    use rstest::rstest;
+
+    fn submission(part: usize, answer: &str, outcome: Outcome) -> Submission {
+        Submission {
+            year: 2022,
+            day: 3,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            message: String::new(),
+            submitted_at: 0,
+        }
+    }
+
+    #[rstest]
+    #[case(
+        "That's the right answer! You are one gold star closer to saving your vacation.",
+        Outcome::Correct
+    )]
+    #[case(
+        "That's not the right answer; your answer is too high. If you're stuck...",
+        Outcome::TooHigh
+    )]
+    #[case(
+        "That's not the right answer; your answer is too low.",
+        Outcome::TooLow
+    )]
+    #[case(
+        "That's not the right answer. If you're stuck, make sure you're using the full input",
+        Outcome::Incorrect
+    )]
+    #[case(
+        "You gave an answer too recently; you have to wait after submitting an answer",
+        Outcome::RateLimited
+    )]
+    #[case(
+        "You don't seem to be solving the right level.  Did you already complete it?",
+        Outcome::WrongLevel
+    )]
+    #[case("Something else", Outcome::Unknown)]
+    fn validate_classify(#[case] message: &str, #[case] expected: Outcome) {
+        assert_eq!(expected, classify(message));
+    }
+
+    #[test]
+    fn test_response_message() {
+        let page = "<html><main>\n<article><p>That's the <em>right answer</em>!</p></article>\n</main></html>";
+
+        assert_eq!("That's the right answer!", response_message(page));
+    }
+
+    #[test]
+    fn test_check() {
+        let history = History(vec![
+            submission(1, "500", Outcome::TooHigh),
+            submission(1, "100", Outcome::TooLow),
+            submission(1, "300", Outcome::Incorrect),
+            submission(1, "200", Outcome::RateLimited),
+            submission(2, "ABC", Outcome::Correct),
+        ]);
+
+        assert!(matches!(history.check(2022, 3, 1, "300"), Check::Refuse(_)));
+        assert!(matches!(history.check(2022, 3, 1, "600"), Check::Warn(_)));
+        assert!(matches!(history.check(2022, 3, 1, "500"), Check::Refuse(_)));
+        assert!(matches!(history.check(2022, 3, 1, "50"), Check::Warn(_)));
+        assert_eq!(Check::Ok, history.check(2022, 3, 1, "250"));
+        assert_eq!(Check::Ok, history.check(2022, 3, 1, "200"));
+        assert_eq!(Check::Ok, history.check(2022, 4, 1, "500"));
+        assert!(matches!(history.check(2022, 3, 2, "XYZ"), Check::Refuse(_)));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, requests) = stub_server(
+            1,
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        );
+        let client = AocClient::new(&url, "abc123");
+        let mut history = History::default();
+
+        let result = submit(&client, &mut history, 2022, 3, 2, "157").unwrap();
+
+        assert_eq!(Outcome::TooLow, result.outcome);
+        assert_eq!(vec![result], history.0);
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2022/day/3/answer HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request.ends_with("level=2&answer=157"));
+    }
+}
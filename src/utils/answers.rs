@@ -0,0 +1,185 @@
+use std::{collections::HashMap, fmt::Display, fs, path::Path};
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+
+use super::solver_types::SolveReport;
+
+/// An answer as written in the answers file - either a number or a string
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// The expected answers for one input. Either part can be left out if it isn't known
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+impl DayAnswers {
+    /// get the expected answers for each part, if we know them
+    pub fn get(&self) -> (Option<String>, Option<String>) {
+        (
+            self.part1.as_ref().map(Answer::to_string),
+            self.part2.as_ref().map(Answer::to_string),
+        )
+    }
+
+    /// check both parts of a solve against these answers
+    pub fn verify(&self, report: &SolveReport) -> (Verdict, Verdict) {
+        let (expected_1, expected_2) = self.get();
+
+        (
+            check(expected_1, &report.part_1),
+            check(expected_2, &report.part_2),
+        )
+    }
+}
+
+/// Known-correct answers for our puzzle inputs, keyed by day (e.g. `[day01]`)
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers(HashMap<String, DayAnswers>);
+
+/// The result of checking one part of a solve against the expected answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    Missing,
+    /// the part wasn't solved, so there's nothing to check
+    Skipped,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected, actual } => {
+                write!(f, "FAIL (expected '{}', got '{}')", expected, actual)
+            }
+            Verdict::Missing => write!(f, "MISSING"),
+            Verdict::Skipped => write!(f, "SKIPPED"),
+        }
+    }
+}
+
+impl Answers {
+    /// Load answers from a toml file
+    pub fn load(file_path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(file_path).map_err(|error| {
+            anyhow!(
+                "Failed to read answers file '{}'. Reason: {}",
+                file_path.display(),
+                error
+            )
+        })?;
+
+        Self::parse(&text)
+    }
+
+    /// Parse answers from the contents of a toml file
+    pub fn parse(text: &str) -> Result<Self> {
+        toml::from_str(text).map_err(|error| anyhow!("Invalid answers file. Reason: {}", error))
+    }
+
+    /// get the expected answers for a given day, if we know them
+    pub fn get(&self, day: usize) -> (Option<String>, Option<String>) {
+        self.day(day).get()
+    }
+
+    /// check both parts of a solve against the expected answers
+    pub fn verify(&self, report: &SolveReport) -> (Verdict, Verdict) {
+        self.day(report.day).verify(report)
+    }
+
+    fn day(&self, day: usize) -> DayAnswers {
+        self.0
+            .get(&format!("day{:02}", day))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+fn check(expected: Option<String>, actual: &Option<String>) -> Verdict {
+    match (expected, actual) {
+        (_, None) => Verdict::Skipped,
+        (None, _) => Verdict::Missing,
+        (Some(expected), Some(actual)) if &expected == actual => Verdict::Pass,
+        (Some(expected), Some(actual)) => Verdict::Fail {
+            expected,
+            actual: actual.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Verdict};
+    use crate::utils::solver_types::SolveReport;
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse(
+            r#"
+[day01]
+part1 = 24000
+part2 = "45000"
+
+[day05]
+part1 = "CMZ"
+"#,
+        )
+        .unwrap();
+
+        let report = SolveReport {
+            day: 1,
+            part_1: Some("24000".to_string()),
+            part_2: Some("1".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            (
+                Verdict::Pass,
+                Verdict::Fail {
+                    expected: "45000".to_string(),
+                    actual: "1".to_string()
+                }
+            ),
+            answers.verify(&report)
+        );
+
+        let report = SolveReport {
+            day: 5,
+            part_1: Some("CMZ".to_string()),
+            part_2: Some("MCD".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!((Verdict::Pass, Verdict::Missing), answers.verify(&report));
+
+        let report = SolveReport {
+            part_2: None,
+            ..report
+        };
+
+        assert_eq!((Verdict::Pass, Verdict::Skipped), answers.verify(&report));
+        assert_eq!((None, None), answers.get(6));
+    }
+}
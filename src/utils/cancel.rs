@@ -0,0 +1,80 @@
+//! Cooperative cancellation for long-running solutions.
+//! The runner installs a token on the thread a day is solved on, and solutions poll `check_cancelled` in their hot loops
+
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use anyhow::{anyhow, Result};
+
+/// A flag shared between the runner and a solve, used to ask the solve to stop early
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Use this token for any solve running on the current thread
+pub fn set_current_token(token: CancellationToken) {
+    CURRENT.with(|current| *current.borrow_mut() = Some(token));
+}
+
+/// Returns an error if the solve running on this thread has been cancelled.
+/// Always succeeds when no token has been installed
+pub fn check_cancelled() -> Result<()> {
+    CURRENT.with(|current| match &*current.borrow() {
+        Some(token) if token.is_cancelled() => Err(anyhow!("Solve was cancelled")),
+        _ => Ok(()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::{check_cancelled, set_current_token, CancellationToken};
+
+    #[test]
+    fn test_cancel() {
+        let token = CancellationToken::default();
+        let worker_token = token.clone();
+
+        let before = thread::spawn(move || {
+            assert!(check_cancelled().is_ok());
+            set_current_token(worker_token);
+            check_cancelled().is_ok()
+        })
+        .join()
+        .unwrap();
+        assert!(before);
+
+        token.cancel();
+        let worker_token = token.clone();
+
+        let after = thread::spawn(move || {
+            set_current_token(worker_token);
+            check_cancelled().is_ok()
+        })
+        .join()
+        .unwrap();
+        assert!(!after);
+
+        // tokens are per-thread, so this thread is unaffected
+        assert!(check_cancelled().is_ok());
+    }
+}
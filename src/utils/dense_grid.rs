@@ -0,0 +1,344 @@
+use std::collections::HashSet;
+
+use super::{
+    grid::{Grid, GridLike, Neighbourhood},
+    point::Pt,
+};
+
+/// A dense grid of values stored contiguously, covering every point in a box from `origin`.
+/// Much faster than `Grid` for fully populated inputs. Any point that isn't set, inside the box or out, gets the default value
+///
+/// ```
+/// use aoc2022::{utils::dense_grid::DenseGrid, GridLike, Pt};
+///
+/// let mut grid = DenseGrid::<char, 2>::from(vec![(Pt([0, 0]), '#'), (Pt([2, 1]), '#')]);
+/// grid.set_default('.');
+///
+/// assert_eq!('#', grid.get_def(&Pt([2, 1])));
+/// // inside the box but not set, so like any other unset point
+/// assert_eq!(None, grid.get(&Pt([1, 0])));
+/// assert_eq!('.', grid.get_def(&Pt([1, 0])));
+/// assert_eq!('.', grid.get_def(&Pt([100, 100])));
+/// assert_eq!(([0, 0], [2, 1]), grid.bounds());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseGrid<T: Copy, const DIMS: usize> {
+    /// the lowest co-ordinate in each dimension
+    origin: Pt<DIMS>,
+    /// how many points the grid covers in each dimension
+    extents: [usize; DIMS],
+    default_val: T,
+    /// the values, with the first dimension changing fastest. `None` for points in the box that aren't set
+    cells: Vec<Option<T>>,
+    /// offsets to the neighbours of points in this N dimensions, in the grid's neighbourhood
+    offsets: HashSet<Pt<DIMS>>,
+    /// cardinal offsets for points in this N dimensions
+    card_offsets: HashSet<Pt<DIMS>>,
+}
+
+impl<T: Default + Copy, const DIMS: usize> Default for DenseGrid<T, DIMS> {
+    fn default() -> Self {
+        Self::new(Pt::default(), [0; DIMS], T::default())
+    }
+}
+
+impl<T: Copy, const DIMS: usize> DenseGrid<T, DIMS> {
+    /// a grid covering `extents` points from `origin` in each dimension, all set to `value`
+    pub fn new(origin: Pt<DIMS>, extents: [usize; DIMS], value: T) -> Self {
+        let mut grid = Self::unset(origin, extents, value);
+        grid.cells.fill(Some(value));
+        grid
+    }
+
+    /// a grid covering `extents` points from `origin` in each dimension, none of them set
+    fn unset(origin: Pt<DIMS>, extents: [usize; DIMS], default: T) -> Self {
+        Self {
+            origin,
+            extents,
+            default_val: default,
+            cells: vec![None; extents.iter().product()],
+            offsets: Pt::<DIMS>::neighbour_offsets(),
+            card_offsets: Pt::<DIMS>::card_offsets(),
+        }
+    }
+
+    /// build a grid covering the bounding box of some points, leaving any gaps unset
+    fn from_points(points: Vec<(Pt<DIMS>, T)>, default: T) -> Self {
+        let mut mins = [isize::MAX; DIMS];
+        let mut maxs = [isize::MIN; DIMS];
+
+        for (pt, _) in &points {
+            for i in 0..DIMS {
+                mins[i] = isize::min(mins[i], pt.0[i]);
+                maxs[i] = isize::max(maxs[i], pt.0[i]);
+            }
+        }
+
+        let mut grid = match points.is_empty() {
+            true => Self::unset(Pt::default(), [0; DIMS], default),
+            false => Self::unset(
+                Pt(mins),
+                std::array::from_fn(|i| (maxs[i] - mins[i]) as usize + 1),
+                default,
+            ),
+        };
+
+        for (pt, v) in points {
+            grid.set(pt, v);
+        }
+        grid
+    }
+
+    /// where a point's value is stored, if it's inside the grid
+    fn index(&self, pt: &Pt<DIMS>) -> Option<usize> {
+        let mut index = 0;
+        let mut stride = 1;
+
+        for i in 0..DIMS {
+            let offset = usize::try_from(pt.0[i] - self.origin.0[i]).ok()?;
+            if offset >= self.extents[i] {
+                return None;
+            }
+            index += offset * stride;
+            stride *= self.extents[i];
+        }
+        Some(index)
+    }
+
+    /// the point a value is stored for
+    fn point(&self, mut index: usize) -> Pt<DIMS> {
+        let mut pt = self.origin;
+
+        for i in 0..DIMS {
+            pt.0[i] += (index % self.extents[i]) as isize;
+            index /= self.extents[i];
+        }
+        pt
+    }
+
+    /// set a value, growing the grid to cover the point if needed.
+    /// Any other new points are left unset
+    pub fn set(&mut self, pt: Pt<DIMS>, value: T) {
+        if self.index(&pt).is_none() {
+            self.grow_to(&pt);
+        }
+        let i = self
+            .index(&pt)
+            .expect("the grid covers the point after growing");
+        self.cells[i] = Some(value);
+    }
+
+    fn grow_to(&mut self, pt: &Pt<DIMS>) {
+        let mut grown = match self.cells.is_empty() {
+            true => Self::unset(*pt, [1; DIMS], self.default_val),
+            false => {
+                let (mins, maxs) = self.bounds();
+                let mins: [isize; DIMS] = std::array::from_fn(|i| mins[i].min(pt.0[i]));
+                Self::unset(
+                    Pt(mins),
+                    std::array::from_fn(|i| (maxs[i].max(pt.0[i]) - mins[i]) as usize + 1),
+                    self.default_val,
+                )
+            }
+        };
+
+        for (pt, v) in self.iter() {
+            let i = grown.index(&pt).expect("the grown grid covers the old one");
+            grown.cells[i] = Some(*v);
+        }
+        self.origin = grown.origin;
+        self.extents = grown.extents;
+        self.cells = grown.cells;
+    }
+
+    /// every set point in the grid, with its value
+    pub fn iter(&self) -> impl Iterator<Item = (Pt<DIMS>, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.as_ref().map(|v| (self.point(i), v)))
+    }
+
+    /// Change which points count as neighbours
+    pub fn set_neighbourhood(&mut self, neighbourhood: Neighbourhood<DIMS>) {
+        self.offsets = neighbourhood.offsets();
+    }
+
+    /// Change the default value
+    pub fn set_default(&mut self, new_default: T) {
+        self.default_val = new_default;
+    }
+}
+
+impl<T: Default + Copy, const DIMS: usize> From<Vec<(Pt<DIMS>, T)>> for DenseGrid<T, DIMS> {
+    fn from(v: Vec<(Pt<DIMS>, T)>) -> Self {
+        Self::from_points(v, T::default())
+    }
+}
+
+/// covers the bounding box of the set points, leaving any gaps unset
+impl<T: Copy, const DIMS: usize> From<Grid<T, DIMS>> for DenseGrid<T, DIMS> {
+    fn from(grid: Grid<T, DIMS>) -> Self {
+        let default = *grid.default_value();
+        let neighbourhood = Neighbourhood::Custom(grid.offsets().iter().copied().collect());
+
+        let mut dense = Self::from_points(grid.grid.into_iter().collect(), default);
+        dense.set_neighbourhood(neighbourhood);
+        dense
+    }
+}
+
+/// every set point in the dense grid is set in the sparse one
+impl<T: Default + Copy, const DIMS: usize> From<DenseGrid<T, DIMS>> for Grid<T, DIMS> {
+    fn from(grid: DenseGrid<T, DIMS>) -> Self {
+        let mut sparse = Grid::from(grid.iter().map(|(pt, v)| (pt, *v)).collect::<Vec<_>>());
+        sparse.set_default(grid.default_val);
+        sparse.set_neighbourhood(Neighbourhood::Custom(grid.offsets.into_iter().collect()));
+        sparse
+    }
+}
+
+impl<T: Copy, const DIMS: usize> GridLike<T, DIMS> for DenseGrid<T, DIMS> {
+    fn get(&self, pt: &Pt<DIMS>) -> Option<&T> {
+        self.index(pt).and_then(|i| self.cells[i].as_ref())
+    }
+
+    fn default_value(&self) -> &T {
+        &self.default_val
+    }
+
+    fn offsets(&self) -> &HashSet<Pt<DIMS>> {
+        &self.offsets
+    }
+
+    fn card_offsets(&self) -> &HashSet<Pt<DIMS>> {
+        &self.card_offsets
+    }
+
+    fn bounds(&self) -> ([isize; DIMS], [isize; DIMS]) {
+        if self.cells.is_empty() {
+            return ([isize::MAX; DIMS], [isize::MIN; DIMS]);
+        }
+
+        (
+            self.origin.0,
+            std::array::from_fn(|i| self.origin.0[i] + self.extents[i] as isize - 1),
+        )
+    }
+
+    /// every set point of `other` is merged
+    fn merge(&mut self, other: DenseGrid<T, DIMS>, merge_function: fn(&T, &T) -> T) {
+        for (pt, v) in other.iter() {
+            let new_val = merge_function(&self.get_def(&pt), v);
+            self.set(pt, new_val);
+        }
+    }
+
+    /// the grid is rebuilt to cover wherever the points end up
+    fn transform(self, transformation: impl Fn(Pt<DIMS>) -> Pt<DIMS>) -> Self {
+        let points = self
+            .iter()
+            .map(|(pt, v)| (transformation(pt), *v))
+            .collect();
+        let mut grid = Self::from_points(points, self.default_val);
+        grid.offsets = self.offsets;
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DenseGrid;
+    use crate::utils::{
+        grid::{Grid, GridLike},
+        point::Pt,
+    };
+
+    #[test]
+    fn test_set() {
+        let mut grid = DenseGrid::new(Pt([0, 0]), [2, 2], 0);
+        grid.set(Pt([1, 1]), 4);
+        grid.set(Pt([-1, 2]), 7);
+
+        assert_eq!(([-1, 0], [1, 2]), grid.bounds());
+        assert_eq!(Some(&4), grid.get(&Pt([1, 1])));
+        assert_eq!(Some(&7), grid.get(&Pt([-1, 2])));
+        assert_eq!(Some(&0), grid.get(&Pt([0, 0])));
+        // growing leaves the new points unset
+        assert_eq!(None, grid.get(&Pt([-1, 0])));
+        assert_eq!(None, grid.get(&Pt([2, 0])));
+        assert_eq!(5, grid.iter().count());
+    }
+
+    #[test]
+    fn test_transform() {
+        let input = DenseGrid::<i32, 2>::from(vec![(Pt([0, 0]), 1), (Pt([1, 0]), 2)]);
+
+        let result = input.transform(|Pt([x, y])| Pt([y, -x]));
+
+        assert_eq!(([0, -1], [0, 0]), result.bounds());
+        assert_eq!(2, result.get_def(&Pt([0, -1])));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut target = DenseGrid::<i32, 2>::from(vec![(Pt([0, 0]), 10), (Pt([1, 0]), 204)]);
+        let to_merge = DenseGrid::<i32, 2>::from(vec![(Pt([1, 0]), 5000), (Pt([1, 1]), 60)]);
+
+        target.merge(to_merge, |a, b| a + b);
+
+        assert_eq!(([0, 0], [1, 1]), target.bounds());
+        assert_eq!(10, target.get_def(&Pt([0, 0])));
+        assert_eq!(5204, target.get_def(&Pt([1, 0])));
+        assert_eq!(0, target.get_def(&Pt([0, 1])));
+        assert_eq!(60, target.get_def(&Pt([1, 1])));
+    }
+
+    #[test]
+    fn test_conversion() {
+        let mut sparse = Grid::<u32, 3>::from(vec![(Pt([0, 0, 0]), 1), (Pt([1, 2, 3]), 9)]);
+        sparse.set_default(5);
+
+        let dense = DenseGrid::from(sparse.clone());
+
+        assert_eq!(2, dense.iter().count());
+        assert_eq!(sparse.bounds(), dense.bounds());
+        assert_eq!(5, dense.get_def(&Pt([1, 1, 1])));
+        assert_eq!(9, dense.get_def(&Pt([1, 2, 3])));
+
+        let back = Grid::from(dense);
+
+        assert_eq!(sparse.grid, back.grid);
+        assert_eq!(5, back.get_def(&Pt([10, 10, 10])));
+        assert_eq!(sparse.bounds(), back.bounds());
+    }
+
+    #[test]
+    fn test_gaps_are_unset() {
+        let sparse = Grid::<u32, 2>::from(vec![(Pt([0, 0]), 1), (Pt([2, 1]), 6)]);
+        let mut dense = DenseGrid::from(sparse);
+
+        assert_eq!(None, dense.get(&Pt([1, 0])));
+
+        // changing the default changes the gaps, like any other unset point
+        dense.set_default(7);
+
+        assert_eq!(7, dense.get_def(&Pt([1, 0])));
+        assert_eq!(7, dense.get_def(&Pt([5, 5])));
+        assert_eq!(6, dense.get_def(&Pt([2, 1])));
+        assert_eq!(
+            vec![(Pt([0, 0]), &1), (Pt([2, 1]), &6)],
+            dense.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_print() {
+        let grid = DenseGrid::<u32, 2>::from(vec![(Pt([0, 0]), 1), (Pt([2, 1]), 6)]);
+
+        assert_eq!(
+            "\n100\n006\n",
+            grid.print(|x| char::from_digit(x, 10).unwrap())
+        );
+    }
+}
@@ -1,12 +1,337 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 
-use super::point::Pt;
+use itertools::Itertools;
 
-#[allow(dead_code)]
+use super::{
+    point::{Orientation, Pt},
+    search::bfs,
+};
+
+/// Which points count as a point's neighbours
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Neighbourhood<const DIMS: usize> {
+    /// only the cardinal (non-diagonal) neighbours, e.g. 4 in 2D
+    VonNeumann,
+    /// every neighbour including diagonals, e.g. 8 in 2D
+    Moore,
+    /// the points at these offsets
+    Custom(Vec<Pt<DIMS>>),
+}
+
+impl<const DIMS: usize> Neighbourhood<DIMS> {
+    /// the offsets from a point to each of its neighbours
+    pub fn offsets(&self) -> HashSet<Pt<DIMS>> {
+        match self {
+            Neighbourhood::VonNeumann => Pt::card_offsets(),
+            Neighbourhood::Moore => Pt::neighbour_offsets(),
+            Neighbourhood::Custom(offsets) => offsets.iter().copied().collect(),
+        }
+    }
+}
+
+/// A connected region of a grid, found by `components`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component<const DIMS: usize> {
+    /// which region this is, counting from 0
+    pub label: usize,
+    pub points: HashSet<Pt<DIMS>>,
+    /// the min and max values of each dimension
+    pub bounds: ([isize; DIMS], [isize; DIMS]),
+}
+
+impl<const DIMS: usize> Component<DIMS> {
+    /// how many points are in the region
+    pub fn size(&self) -> usize {
+        self.points.len()
+    }
+}
+
+/// The operations shared by the sparse `Grid` and the dense `DenseGrid`
+pub trait GridLike<T: Copy, const DIMS: usize> {
+    /// get a value at the specified coordinates, if it's set
+    fn get(&self, pt: &Pt<DIMS>) -> Option<&T>;
+
+    /// get a value at the specified coordinates or the default
+    fn get_def(&self, pt: &Pt<DIMS>) -> T {
+        *self.get(pt).unwrap_or(self.default_value())
+    }
+
+    /// the value of any point that isn't set
+    fn default_value(&self) -> &T;
+
+    /// the offsets to each neighbour of a point in this grid's neighbourhood. By default, every neighbour including diagonals
+    fn offsets(&self) -> &HashSet<Pt<DIMS>>;
+
+    /// the offsets to each cardinal (non-diagonal) neighbour of a point
+    fn card_offsets(&self) -> &HashSet<Pt<DIMS>>;
+
+    /// get the min and max values of each dimension
+    fn bounds(&self) -> ([isize; DIMS], [isize; DIMS]);
+
+    /// merge one grid into this one, using the specified merge_function
+    fn merge(&mut self, other: Self, merge_function: fn(&T, &T) -> T)
+    where
+        Self: Sized;
+
+    /// apply a transformation to every point in a grid
+    fn transform(self, transformation: impl Fn(Pt<DIMS>) -> Pt<DIMS>) -> Self
+    where
+        Self: Sized;
+
+    /// move the grid so that its lowest co-ordinate in each dimension is 0
+    fn normalize(self) -> Self
+    where
+        Self: Sized,
+    {
+        let (mins, _) = self.bounds();
+        self.transform(|pt| pt - Pt(mins))
+    }
+
+    /// rotate by `turns` quarter turns in the plane of two axes (see `Pt::rotate`), then normalize
+    ///
+    /// ```
+    /// use aoc2022::{Grid, GridLike, Pt};
+    ///
+    /// let grid = Grid::<char, 2>::from(vec![(Pt([0, 0]), 'a'), (Pt([1, 0]), 'b'), (Pt([2, 1]), 'c')]);
+    ///
+    /// // clockwise, with y increasing downwards
+    /// let rotated = grid.rotate(0, 1, 1);
+    /// assert_eq!(([0, 0], [1, 2]), rotated.bounds());
+    /// assert_eq!(Some(&'a'), rotated.get(&Pt([1, 0])));
+    /// assert_eq!(Some(&'c'), rotated.get(&Pt([0, 2])));
+    /// ```
+    fn rotate(self, from: usize, to: usize, turns: isize) -> Self
+    where
+        Self: Sized,
+    {
+        self.transform(|pt| pt.rotate(from, to, turns)).normalize()
+    }
+
+    /// mirror along an axis, then normalize
+    fn flip(self, axis: usize) -> Self
+    where
+        Self: Sized,
+    {
+        self.transform(|pt| pt.reflect(axis)).normalize()
+    }
+
+    /// move into an orientation, then normalize. Use with `Orientation::all` to try every way round
+    fn orient(self, orientation: &Orientation<DIMS>) -> Self
+    where
+        Self: Sized,
+    {
+        self.transform(|pt| orientation.apply(&pt)).normalize()
+    }
+
+    /// the set neighbours of a point in this grid's neighbourhood, with their values
+    ///
+    /// ```
+    /// use aoc2022::{utils::grid::Neighbourhood, Grid, GridLike, Pt};
+    ///
+    /// let mut grid = Grid::<char, 2>::from(vec![(Pt([0, 0]), 'a'), (Pt([1, 1]), 'b'), (Pt([3, 3]), 'c')]);
+    /// assert_eq!(vec![(Pt([1, 1]), &'b')], grid.neighbours(&Pt([0, 0])));
+    ///
+    /// grid.set_neighbourhood(Neighbourhood::VonNeumann);
+    /// assert!(grid.neighbours(&Pt([0, 0])).is_empty());
+    /// ```
+    fn neighbours(&self, pt: &Pt<DIMS>) -> Vec<(Pt<DIMS>, &T)> {
+        set_at(self, pt, self.offsets())
+    }
+
+    /// the set cardinal (non-diagonal) neighbours of a point, with their values
+    fn card_neighbours(&self, pt: &Pt<DIMS>) -> Vec<(Pt<DIMS>, &T)> {
+        set_at(self, pt, self.card_offsets())
+    }
+
+    /// the neighbours of a point in this grid's neighbourhood that are within its bounds, with their values or the default.
+    /// A sparse `Grid` has to find its bounds each time, so prefer `neighbours` in hot loops
+    fn neighbours_in_bounds(&self, pt: &Pt<DIMS>) -> Vec<(Pt<DIMS>, &T)> {
+        let bounds = self.bounds();
+
+        self.offsets()
+            .iter()
+            .map(|off| pt + off)
+            .filter(|n| within(n, &bounds))
+            .map(|n| (n, self.get(&n).unwrap_or(self.default_value())))
+            .collect()
+    }
+
+    /// the values of all of a point's neighbours in this grid's neighbourhood, with the default for any that aren't set
+    fn neighbour_values(&self, pt: &Pt<DIMS>) -> Vec<&T> {
+        self.offsets()
+            .iter()
+            .map(|off| self.get(&(pt + off)).unwrap_or(self.default_value()))
+            .collect()
+    }
+
+    /// every point reachable from `start` through this grid's neighbourhood without leaving `bounds`,
+    /// passing only through points whose values (or the default, if they aren't set) match `predicate`
+    ///
+    /// ```
+    /// use aoc2022::{utils::grid::Neighbourhood, Grid, GridLike, Pt};
+    ///
+    /// // a ring of walls
+    /// let walls = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+    /// let mut grid = Grid::<bool, 2>::from(walls.map(|(x, y)| (Pt([x, y]), true)).to_vec());
+    /// grid.set_neighbourhood(Neighbourhood::VonNeumann);
+    ///
+    /// let inside = grid.flood_fill(Pt([0, 0]), |wall| !wall, grid.bounds());
+    /// assert_eq!(1, inside.len());
+    ///
+    /// // diagonal steps slip between the walls, out to the corners of the bounds
+    /// grid.set_neighbourhood(Neighbourhood::Moore);
+    /// let escaped = grid.flood_fill(Pt([0, 0]), |wall| !wall, grid.bounds());
+    /// assert_eq!(5, escaped.len());
+    /// ```
+    fn flood_fill(
+        &self,
+        start: Pt<DIMS>,
+        predicate: impl Fn(&T) -> bool,
+        bounds: ([isize; DIMS], [isize; DIMS]),
+    ) -> HashSet<Pt<DIMS>>
+    where
+        Self: Sized,
+    {
+        let fillable = |pt: &Pt<DIMS>| within(pt, &bounds) && predicate(&self.get_def(pt));
+        if !fillable(&start) {
+            return HashSet::new();
+        }
+
+        bfs(start, |pt| {
+            self.offsets()
+                .iter()
+                .map(|off| pt + off)
+                .filter(fillable)
+                .collect::<Vec<_>>()
+        })
+        .distances
+        .into_keys()
+        .collect()
+    }
+
+    /// split the points within this grid's bounds whose values match `predicate` into connected regions,
+    /// using this grid's neighbourhood. Unset points within the bounds are included if the default matches
+    ///
+    /// ```
+    /// use aoc2022::{Grid, GridLike, Pt};
+    ///
+    /// let grid = Grid::<char, 2>::from(vec![
+    ///     (Pt([0, 0]), '#'),
+    ///     (Pt([1, 1]), '#'),
+    ///     (Pt([4, 0]), '#'),
+    ///     (Pt([4, 1]), '.'),
+    /// ]);
+    /// let regions = grid.components(|c| *c == '#');
+    ///
+    /// assert_eq!(vec![2, 1], regions.iter().map(|r| r.size()).collect::<Vec<_>>());
+    /// assert_eq!(([0, 0], [1, 1]), regions[0].bounds);
+    /// ```
+    fn components(&self, predicate: impl Fn(&T) -> bool) -> Vec<Component<DIMS>>
+    where
+        Self: Sized,
+    {
+        let bounds = self.bounds();
+        let mut seen = HashSet::new();
+        let mut components = vec![];
+
+        for pt in points_in(&bounds) {
+            if seen.contains(&pt) || !predicate(&self.get_def(&pt)) {
+                continue;
+            }
+
+            let points = self.flood_fill(pt, &predicate, bounds);
+            seen.extend(points.iter().copied());
+            components.push(Component {
+                label: components.len(),
+                bounds: bounds_of(points.iter()),
+                points,
+            });
+        }
+
+        components
+    }
+
+    /// print a 2d grid using a given function for representing points
+    fn print(&self, to_printable: fn(T) -> char) -> String
+    where
+        Self: GridLike<T, 2>,
+    {
+        let mut res = String::from("\n");
+
+        let ([min_x, min_y], [max_x, max_y]) = GridLike::<T, 2>::bounds(self);
+
+        for y in min_y..max_y + 1 {
+            for x in min_x..max_x + 1 {
+                res.push(to_printable(GridLike::<T, 2>::get_def(self, &Pt([x, y]))));
+            }
+            res.push('\n');
+        }
+
+        res
+    }
+}
+
+/// whether a point is within some bounds, inclusive
+fn within<const DIMS: usize>(pt: &Pt<DIMS>, (mins, maxs): &([isize; DIMS], [isize; DIMS])) -> bool {
+    (0..DIMS).all(|i| (mins[i]..=maxs[i]).contains(&pt.0[i]))
+}
+
+/// every point within some bounds, inclusive
+fn points_in<const DIMS: usize>(
+    (mins, maxs): &([isize; DIMS], [isize; DIMS]),
+) -> impl Iterator<Item = Pt<DIMS>> {
+    (0..DIMS)
+        .map(|i| mins[i]..=maxs[i])
+        .multi_cartesian_product()
+        .map(|pt| Pt(pt.try_into().unwrap()))
+}
+
+/// the min and max values of each dimension of some points
+fn bounds_of<'a, const DIMS: usize>(
+    points: impl Iterator<Item = &'a Pt<DIMS>>,
+) -> ([isize; DIMS], [isize; DIMS]) {
+    let mut mins = [isize::MAX; DIMS];
+    let mut maxs = [isize::MIN; DIMS];
+
+    for pt in points {
+        for i in 0..DIMS {
+            mins[i] = isize::min(mins[i], pt.0[i]);
+            maxs[i] = isize::max(maxs[i], pt.0[i]);
+        }
+    }
+    (mins, maxs)
+}
+
+/// the set points at some offsets from a point, with their values
+fn set_at<'a, T: Copy, const DIMS: usize>(
+    grid: &'a (impl GridLike<T, DIMS> + ?Sized),
+    pt: &Pt<DIMS>,
+    offsets: &HashSet<Pt<DIMS>>,
+) -> Vec<(Pt<DIMS>, &'a T)> {
+    offsets
+        .iter()
+        .map(|off| pt + off)
+        .filter_map(|n| grid.get(&n).map(|v| (n, v)))
+        .collect()
+}
+
+/// A sparse grid of values keyed by `Pt`, with a default value for any point that isn't set
+///
+/// ```
+/// use aoc2022::{Grid, GridLike, Pt};
+///
+/// let mut grid = Grid::<char, 2>::from(vec![(Pt([0, 0]), '#'), (Pt([2, 1]), '#')]);
+/// grid.set_default('.');
+///
+/// assert_eq!('#', grid.get_def(&Pt([2, 1])));
+/// assert_eq!('.', grid.get_def(&Pt([100, 100])));
+/// assert_eq!(([0, 0], [2, 1]), grid.bounds());
+/// assert_eq!("\n#..\n..#\n", grid.print(|c| c));
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Grid<T: Copy, const DIMS: usize> {
-    /// neighbour offsets for points in this N dimensions
+    /// offsets to the neighbours of points in this N dimensions, in the grid's neighbourhood
     offsets: HashSet<Pt<DIMS>>,
     /// cardinal offsets for points in this N dimensions
     pub card_offsets: HashSet<Pt<DIMS>>,
@@ -50,28 +375,43 @@ impl<T: Default + Copy, const DIMS: usize> From<Vec<(Vec<isize>, T)>> for Grid<T
     }
 }
 
-#[allow(dead_code)]
 impl<T: Copy, const DIMS: usize> Grid<T, DIMS> {
-    /// get a value at the specified coordinates or the default
-    pub fn get_def(&self, pt: &Pt<DIMS>) -> T {
-        *self.grid.get(pt).unwrap_or(&self.default_val)
+    /// Change which points count as neighbours
+    pub fn set_neighbourhood(&mut self, neighbourhood: Neighbourhood<DIMS>) {
+        self.offsets = neighbourhood.offsets();
     }
 
     /// Change the default value
     pub fn set_default(&mut self, new_default: T) {
         self.default_val = new_default;
     }
+}
+
+impl<T: Copy, const DIMS: usize> GridLike<T, DIMS> for Grid<T, DIMS> {
+    fn get(&self, pt: &Pt<DIMS>) -> Option<&T> {
+        self.grid.get(pt)
+    }
 
-    /// merge one grid into this one, using the specified merge_function
-    pub fn merge(&mut self, other: Grid<T, DIMS>, merge_function: fn(&T, &T) -> T) {
+    fn default_value(&self) -> &T {
+        &self.default_val
+    }
+
+    fn offsets(&self) -> &HashSet<Pt<DIMS>> {
+        &self.offsets
+    }
+
+    fn card_offsets(&self) -> &HashSet<Pt<DIMS>> {
+        &self.card_offsets
+    }
+
+    fn merge(&mut self, other: Grid<T, DIMS>, merge_function: fn(&T, &T) -> T) {
         other.grid.into_iter().for_each(|(k, v)| {
             let new_val = merge_function(self.grid.get(&k).unwrap_or(&self.default_val), &v);
             self.grid.insert(k, new_val);
         });
     }
 
-    /// apply a transformation to every point in a grid
-    pub fn transform(mut self, transformation: fn(Pt<DIMS>) -> Pt<DIMS>) -> Self {
+    fn transform(mut self, transformation: impl Fn(Pt<DIMS>) -> Pt<DIMS>) -> Self {
         let mut new_grid = HashMap::default();
         self.grid.into_iter().for_each(|(k, v)| {
             new_grid.insert(transformation(k), v);
@@ -80,43 +420,21 @@ impl<T: Copy, const DIMS: usize> Grid<T, DIMS> {
         self
     }
 
-    /// get the min and max values of each dimension
-    pub fn bounds(&self) -> ([isize; DIMS], [isize; DIMS]) {
-        let mut mins = [isize::MAX; DIMS];
-        let mut maxs = [isize::MIN; DIMS];
-
-        for k in self.grid.keys() {
-            for i in 0..DIMS {
-                mins[i] = isize::min(mins[i], k.0[i]);
-                maxs[i] = isize::max(maxs[i], k.0[i]);
-            }
-        }
-        (mins, maxs)
-    }
-}
-
-impl<T: Copy> Grid<T, 2> {
-    /// print a 2d grid using a given function for representing points
-    pub fn print(&self, to_printable: fn(T) -> char) -> String {
-        let mut res = String::from("\n");
-
-        let ([min_x, min_y], [max_x, max_y]) = self.bounds();
-
-        for y in min_y..max_y + 1 {
-            for x in min_x..max_x + 1 {
-                res.push(to_printable(self.get_def(&Pt([x, y]))));
-            }
-            res.push('\n');
-        }
-
-        res
+    fn bounds(&self) -> ([isize; DIMS], [isize; DIMS]) {
+        bounds_of(self.grid.keys())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Grid;
-    use crate::utils::point::Pt;
+    use std::collections::HashSet;
+
+    use super::{Grid, GridLike, Neighbourhood};
+    use crate::utils::{
+        dense_grid::DenseGrid,
+        point::{Orientation, Pt},
+    };
+    use rstest::rstest;
 
     #[test]
     fn test_transform() {
@@ -153,6 +471,91 @@ mod tests {
         assert_eq!(expected, target);
     }
 
+    #[rstest]
+    #[case(Neighbourhood::Moore, 2, 8, 3)]
+    #[case(Neighbourhood::VonNeumann, 2, 4, 2)]
+    #[case(Neighbourhood::Custom(vec![Pt([2, 0]), Pt([0, 1])]), 1, 2, 2)]
+    fn validate_neighbours(
+        #[case] neighbourhood: Neighbourhood<2>,
+        #[case] set: usize,
+        #[case] all: usize,
+        #[case] in_bounds: usize,
+    ) {
+        // 1 2
+        // 3 .
+        let mut grid = Grid::<u32, 2>::from(vec![
+            (Pt([0, 0]), 1),
+            (Pt([1, 0]), 2),
+            (Pt([0, 1]), 3),
+            (Pt([5, 5]), 9),
+        ]);
+        grid.set_neighbourhood(neighbourhood);
+        let corner = Pt([1, 1]);
+
+        assert_eq!(set, grid.neighbours(&Pt([0, 0])).len());
+        assert_eq!(2, grid.card_neighbours(&Pt([0, 0])).len());
+        assert_eq!(all, grid.neighbour_values(&corner).len());
+        assert_eq!(
+            set,
+            grid.neighbour_values(&Pt([0, 0]))
+                .into_iter()
+                .filter(|v| **v != 0)
+                .count()
+        );
+
+        // the 9 stretches the bounds, so the whole neighbourhood is inside them away from the edge
+        assert_eq!(all, grid.neighbours_in_bounds(&corner).len());
+        assert_eq!(in_bounds, grid.neighbours_in_bounds(&Pt([0, 0])).len());
+    }
+
+    #[test]
+    fn test_components() {
+        // two separate rods of cubes, and a lone cube touching one of them diagonally
+        let mut grid = Grid::<bool, 3>::from(
+            [
+                [0, 0, 0],
+                [0, 0, 1],
+                [0, 0, 2],
+                [3, 3, 0],
+                [3, 3, 1],
+                [1, 1, 3],
+            ]
+            .map(|pt| (Pt(pt), true))
+            .to_vec(),
+        );
+
+        let sizes = |grid: &Grid<bool, 3>| {
+            grid.components(|cube| *cube)
+                .iter()
+                .map(|c| c.size())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec![4, 2], sizes(&grid));
+        grid.set_neighbourhood(Neighbourhood::VonNeumann);
+        assert_eq!(vec![3, 1, 2], sizes(&grid));
+
+        let rods = grid.components(|cube| *cube);
+        assert_eq!(
+            vec![0, 1, 2],
+            rods.iter().map(|c| c.label).collect::<Vec<_>>()
+        );
+        assert_eq!(([3, 3, 0], [3, 3, 1]), rods[2].bounds);
+
+        // the gaps are one region, since unset points hold the default
+        assert_eq!(1, grid.components(|cube| !cube).len());
+
+        // the fill stops at the bounds it's given
+        let bounds = ([0, 0, 0], [0, 0, 1]);
+        assert_eq!(
+            2,
+            grid.flood_fill(Pt([0, 0, 0]), |cube| *cube, bounds).len()
+        );
+        assert!(grid
+            .flood_fill(Pt([1, 0, 0]), |cube| *cube, bounds)
+            .is_empty());
+    }
+
     #[test]
     fn test_print() {
         let expected = r#"
@@ -178,4 +581,48 @@ mod tests {
 
         assert_eq!(expected, result);
     }
+
+    fn digits() -> Grid<u32, 2> {
+        Grid::from(vec![
+            (Pt([5, 5]), 1),
+            (Pt([6, 5]), 2),
+            (Pt([7, 5]), 3),
+            (Pt([5, 6]), 4),
+            (Pt([6, 6]), 5),
+            (Pt([7, 6]), 6),
+        ])
+    }
+
+    #[rstest]
+    #[case(1, "\n41\n52\n63\n")]
+    #[case(2, "\n654\n321\n")]
+    #[case(-1, "\n36\n25\n14\n")]
+    #[case(4, "\n123\n456\n")]
+    fn validate_rotate(#[case] turns: isize, #[case] expected: &str) {
+        let rotated = digits().rotate(0, 1, turns);
+
+        assert_eq!(0, rotated.bounds().0.into_iter().min().unwrap());
+        assert_eq!(
+            expected,
+            rotated.print(|x| char::from_digit(x, 10).unwrap())
+        );
+
+        let dense = DenseGrid::from(digits()).rotate(0, 1, turns);
+        assert_eq!(expected, dense.print(|x| char::from_digit(x, 10).unwrap()));
+    }
+
+    #[test]
+    fn test_flip() {
+        let print = |grid: Grid<u32, 2>| grid.print(|x| char::from_digit(x, 10).unwrap());
+
+        assert_eq!("\n321\n654\n", print(digits().flip(0)));
+        assert_eq!("\n456\n123\n", print(digits().flip(1)));
+
+        // an asymmetric grid looks different in every orientation
+        let oriented: HashSet<String> = Orientation::all()
+            .map(|o| print(digits().orient(&o)))
+            .collect();
+        assert_eq!(8, oriented.len());
+        assert!(oriented.contains("\n14\n25\n36\n"));
+    }
 }
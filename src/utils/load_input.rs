@@ -1,42 +1,125 @@
-use super::grid::Grid;
+//! Helper functions for loading common input styles
 
-/**
-Helper functions for loading common input styles
-*/
+use lazy_static::lazy_static;
+use regex::Regex;
 
-/// Load a string representation of a 2D grid into a Grid object
+use super::{
+    parse::{ParseError, Source},
+    point::Pt,
+};
+
+lazy_static! {
+    static ref BLANK_LINES: Regex = Regex::new(r"\r?\n([ \t]*\r?\n)+").unwrap();
+}
+
+/// Load a string representation of a 2D grid into a `Grid` or a `DenseGrid`
 /// NOTE: We always assume the top-left elemnt is 0,0, and that x increases rightward and y increases downward
-pub fn load_2d_grid<T: Copy + Default>(input: &str, to_value: fn(char) -> T) -> Grid<T, 2> {
+/// Any character `to_value` rejects is reported as an error, saying what was `expected` instead.
+/// Every row must be as wide as the first, so a `DenseGrid` never has gaps to fill with its default
+///
+/// ```
+/// use aoc2022::{utils::load_input::load_2d_grid, DenseGrid, Grid, GridLike, Pt};
+///
+/// let grid: Grid<u32, 2> = load_2d_grid("12\n34", "a digit", |c| c.to_digit(10)).unwrap();
+/// assert_eq!(3, grid.get_def(&Pt([0, 1])));
+///
+/// let dense: DenseGrid<u32, 2> = load_2d_grid("12\n34\n", "a digit", |c| c.to_digit(10)).unwrap();
+/// assert_eq!(4, dense.get_def(&Pt([1, 1])));
+///
+/// let error = load_2d_grid::<_, Grid<_, 2>>("12\n3x", "a digit", |c| c.to_digit(10)).unwrap_err();
+/// assert_eq!((2, 2), (error.line, error.column));
+///
+/// let error = load_2d_grid::<_, Grid<_, 2>>("12\n3", "a digit", |c| c.to_digit(10)).unwrap_err();
+/// assert_eq!((2, 2), (error.line, error.column));
+/// ```
+pub fn load_2d_grid<T, G: From<Vec<(Pt<2>, T)>>>(
+    input: &str,
+    expected: &str,
+    to_value: impl Fn(char) -> Option<T>,
+) -> Result<G, ParseError> {
+    let source = Source(input);
     let mut pairs = vec![];
-    for (y, line) in (0..).zip(input.split('\n')) {
-        for (x, c) in (0..).zip(line.chars()) {
-            pairs.push((vec![x, y], to_value(c)));
+    let mut width = None;
+
+    for (y, line) in (0..).zip(input.trim_end_matches(['\r', '\n']).lines()) {
+        let mut x = 0;
+        for (i, c) in line.char_indices() {
+            if let Some(width) = width.filter(|width| x >= *width) {
+                return Err(source.error(&line[i..], row_of(width)));
+            }
+            match to_value(c) {
+                Some(value) => pairs.push((Pt([x, y]), value)),
+                None => return Err(source.error(&line[i..], expected)),
+            }
+            x += 1;
+        }
+
+        match width {
+            None => width = Some(x),
+            Some(width) if x < width => return Err(source.error_after(line, row_of(width))),
+            _ => (),
         }
     }
 
-    Grid::from(pairs)
+    Ok(G::from(pairs))
+}
+
+fn row_of(width: isize) -> String {
+    format!("a row of {} characters, like the first", width)
 }
 
 /// load values from an \n-seperated list
-pub fn load_lines<T>(input: &str, to_value: fn(&str) -> T) -> Vec<T> {
+///
+/// ```
+/// use aoc2022::utils::load_input::load_lines;
+///
+/// let loaded: Result<Vec<u32>, _> = load_lines("1\n2\n3", |l| l.parse());
+/// assert_eq!(vec![1, 2, 3], loaded.unwrap());
+/// ```
+pub fn load_lines<T, E>(
+    input: &str,
+    to_value: impl FnMut(&str) -> Result<T, E>,
+) -> Result<Vec<T>, E> {
     input.lines().map(to_value).collect()
 }
 
 /// load values from a list of \n-seperated list
-pub fn load_segmented_lines<T>(
+///
+/// ```
+/// use aoc2022::utils::load_input::load_segmented_lines;
+///
+/// let elves: Result<Vec<Vec<u32>>, _> = load_segmented_lines("1\n2\n\n3", "\n\n", |l| l.parse());
+/// assert_eq!(vec![vec![1, 2], vec![3]], elves.unwrap());
+/// ```
+pub fn load_segmented_lines<T, E>(
     input: &str,
     split_on: &str,
-    to_value: fn(&str) -> T,
-) -> Vec<Vec<T>> {
+    mut to_value: impl FnMut(&str) -> Result<T, E>,
+) -> Result<Vec<Vec<T>>, E> {
     input
         .split(split_on)
-        .map(|seg| load_lines(seg, to_value))
+        .map(|seg| load_lines(seg, &mut to_value))
         .collect()
 }
 
+/// split the input into blocks separated by blank lines, as slices of the input.
+/// Works with \r\n line endings too, and skips empty blocks, like those left by trailing blank lines
+///
+/// ```
+/// use aoc2022::utils::load_input::load_blocks;
+///
+/// let blocks = load_blocks("1\r\n2\r\n\r\n3\n\n\n").collect::<Vec<_>>();
+/// assert_eq!(vec!["1\r\n2", "3"], blocks);
+/// ```
+pub fn load_blocks(input: &str) -> impl Iterator<Item = &str> {
+    BLANK_LINES
+        .split(input)
+        .filter(|block| !block.trim().is_empty())
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::utils::{grid::Grid, load_input::load_2d_grid, point::Pt};
+    use crate::utils::{dense_grid::DenseGrid, grid::Grid, load_input::load_2d_grid, point::Pt};
 
     #[test]
     fn test_load_2d_grid() {
@@ -59,8 +142,27 @@ mod tests {
             (Pt([2, 2]), 9),
         ]);
 
-        let result = load_2d_grid(&input, |v| v.to_digit(10).unwrap());
+        let result: Grid<u32, 2> = load_2d_grid(&input, "a digit", |v| v.to_digit(10)).unwrap();
 
         assert_eq!(expected.grid, result.grid);
+
+        let dense: DenseGrid<u32, 2> = load_2d_grid(&input, "a digit", |v| v.to_digit(10)).unwrap();
+
+        assert_eq!(DenseGrid::from(expected), dense);
+
+        let error =
+            load_2d_grid::<_, Grid<_, 2>>("123\n4.6", "a digit", |v| v.to_digit(10)).unwrap_err();
+        assert_eq!((2, 2, "4.6"), (error.line, error.column, &*error.text));
+
+        // a short row would leave a gap in a dense grid
+        let error =
+            load_2d_grid::<_, DenseGrid<_, 2>>("123\n45\n789", "a digit", |v| v.to_digit(10))
+                .unwrap_err();
+        assert_eq!((2, 3), (error.line, error.column));
+        assert_eq!("a row of 3 characters, like the first", error.expected);
+
+        let error = load_2d_grid::<_, Grid<_, 2>>("12\r\n345\r\n", "a digit", |v| v.to_digit(10))
+            .unwrap_err();
+        assert_eq!((2, 3), (error.line, error.column));
     }
 }
@@ -1,4 +1,10 @@
+pub mod answers;
+pub mod cancel;
+pub mod dense_grid;
 pub mod grid;
 pub mod load_input;
+pub mod params;
+pub mod parse;
 pub mod point;
+pub mod search;
 pub mod solver_types;
@@ -0,0 +1,151 @@
+//! Puzzle constants that differ between the examples and the real input, e.g. which row day 15 checks
+
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+/// A param's name and its default value, for listing the params a day takes
+pub type ParamInfo = (&'static str, String);
+
+/// A typed puzzle constant, with the value it takes for the real input
+pub struct Param<T> {
+    pub name: &'static str,
+    pub default: T,
+}
+
+impl<T> Param<T> {
+    pub const fn new(name: &'static str, default: T) -> Self {
+        Self { name, default }
+    }
+}
+
+impl<T: FromStr + Display + Copy> Param<T>
+where
+    T::Err: Display,
+{
+    /// this param's value in `params`, or its default if it wasn't given
+    ///
+    /// ```
+    /// use aoc2022::utils::params::{Param, Params};
+    ///
+    /// const TARGET: Param<isize> = Param::new("target", 2_000_000);
+    ///
+    /// assert_eq!(2_000_000, TARGET.get(&Params::default()).unwrap());
+    /// assert_eq!(10, TARGET.get(&Params::parse(&["target=10"]).unwrap()).unwrap());
+    /// ```
+    pub fn get(&self, params: &Params) -> Result<T> {
+        match params.0.get(self.name) {
+            None => Ok(self.default),
+            Some(value) => value.parse().map_err(|err| {
+                anyhow!(
+                    "Invalid value '{}' for param '{}'. Reason: {}",
+                    value,
+                    self.name,
+                    err
+                )
+            }),
+        }
+    }
+
+    /// this param's name and default, for listing it
+    pub fn info(&self) -> ParamInfo {
+        (self.name, self.default.to_string())
+    }
+}
+
+/// The values given for a day's params, by name. Any param that isn't given takes its default
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(pub HashMap<String, String>);
+
+/// parse a `name=value` pair
+pub fn parse_pair(pair: &str) -> Result<(String, String)> {
+    match pair.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(anyhow!(
+            "Invalid param '{}'. Params are given as name=value",
+            pair
+        )),
+    }
+}
+
+impl Params {
+    /// parse a list of `name=value` pairs
+    pub fn parse<S: AsRef<str>>(pairs: &[S]) -> Result<Self> {
+        pairs
+            .iter()
+            .map(|pair| parse_pair(pair.as_ref()))
+            .collect::<Result<_>>()
+            .map(Params)
+    }
+
+    /// check every given param is one a day takes, so typos aren't silently ignored
+    pub fn check(&self, known: &[ParamInfo]) -> Result<()> {
+        let unknown = self
+            .0
+            .keys()
+            .filter(|name| !known.iter().any(|(known, _)| known == name))
+            .sorted()
+            .collect::<Vec<_>>();
+
+        if unknown.is_empty() {
+            return Ok(());
+        }
+
+        let takes = match known.is_empty() {
+            true => "This day takes no params".to_string(),
+            false => format!(
+                "This day takes: {}",
+                known
+                    .iter()
+                    .map(|(name, default)| format!("{} (default {})", name, default))
+                    .join(", ")
+            ),
+        };
+
+        Err(anyhow!(
+            "Unknown param '{}'. {}",
+            unknown.iter().join("', '"),
+            takes
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Param, Params};
+    use rstest::rstest;
+
+    const ROUNDS: Param<usize> = Param::new("rounds", 20);
+
+    #[rstest]
+    #[case(&[], 20)]
+    #[case(&["rounds=10000"], 10000)]
+    #[case(&[" rounds = 5 ", "other=x"], 5)]
+    fn validate_get(#[case] pairs: &[&str], #[case] expected: usize) {
+        let params = Params::parse(pairs).unwrap();
+
+        assert_eq!(expected, ROUNDS.get(&params).unwrap());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(Params::parse(&["rounds"]).is_err());
+        assert!(Params::parse(&["=5"]).is_err());
+
+        let params = Params::parse(&["rounds=lots"]).unwrap();
+        assert!(ROUNDS.get(&params).is_err());
+
+        let known = [ROUNDS.info()];
+        assert!(params.check(&known).is_ok());
+
+        let typo = Params::parse(&["round=5"]).unwrap();
+        assert_eq!(
+            "Unknown param 'round'. This day takes: rounds (default 20)",
+            typo.check(&known).unwrap_err().to_string()
+        );
+        assert!(typo.check(&[]).is_err());
+    }
+}
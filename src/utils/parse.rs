@@ -0,0 +1,213 @@
+//! Errors for malformed puzzle input, pointing at where in the input things went wrong
+
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use regex::{Captures, Regex};
+
+/// A piece of the input that couldn't be parsed, and what was expected there instead
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// the line the bad text is on, counting from 1
+    pub line: usize,
+    /// the character the bad text starts at, counting from 1
+    pub column: usize,
+    /// the whole of the bad line
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// an error at `part`, which should be a slice of `input`.
+    /// Anything else is reported at the start of the input
+    ///
+    /// ```
+    /// use aoc2022::utils::parse::ParseError;
+    ///
+    /// let input = "A X\nQ Y";
+    /// let error = ParseError::at(input, &input[4..5], "A, B or C");
+    ///
+    /// assert_eq!((2, 1), (error.line, error.column));
+    /// assert_eq!("Q Y", error.text);
+    /// ```
+    pub fn at(input: &str, part: &str, expected: impl Display) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + part.len() <= input.len())
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            expected: expected.to_string(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let gutter = self.line.to_string().len();
+
+        writeln!(
+            f,
+            "Invalid input on line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(
+            f,
+            "{:gutter$} | {:>column$}",
+            "",
+            "^",
+            gutter = gutter,
+            column = self.column
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// The whole of a puzzle input, for building errors that point into it
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a>(pub &'a str);
+
+impl<'a> Source<'a> {
+    /// an error at `part` of this input
+    pub fn error(&self, part: &str, expected: impl Display) -> ParseError {
+        ParseError::at(self.0, part, expected)
+    }
+
+    /// an error just after the end of `part`, for when something is missing
+    pub fn error_after(&self, part: &str, expected: impl Display) -> ParseError {
+        self.error(&part[part.len()..], expected)
+    }
+
+    /// parse `part` of this input into a `T`
+    ///
+    /// ```
+    /// use aoc2022::utils::parse::Source;
+    ///
+    /// let input = "1000\n20x0";
+    /// let source = Source(input);
+    ///
+    /// assert_eq!(1000, source.parse::<u32>(&input[..4], "a number").unwrap());
+    /// assert_eq!(
+    ///     "Invalid input on line 2, column 1: expected a number\n2 | 20x0\n  | ^",
+    ///     source.parse::<u32>(&input[5..], "a number").unwrap_err().to_string()
+    /// );
+    /// ```
+    pub fn parse<T: FromStr>(&self, part: &str, expected: impl Display) -> Result<T, ParseError> {
+        part.parse().map_err(|_| self.error(part, expected))
+    }
+
+    /// split `part` of this input around the first `delimiter`
+    pub fn split_once(
+        &self,
+        part: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(delimiter)
+            .ok_or_else(|| self.error_after(part, format!("'{}'", delimiter.escape_debug())))
+    }
+
+    /// match `part` of this input against a regex, which should describe the whole of the line
+    pub fn captures(&self, part: &'a str, regex: &Regex) -> Result<Captures<'a>, ParseError> {
+        regex
+            .captures(part)
+            .ok_or_else(|| self.error(part, format!("a line matching '{}'", regex)))
+    }
+
+    /// the text matched by each of a regex's `N` groups. Groups that didn't take part in the match are empty
+    ///
+    /// ```
+    /// use aoc2022::utils::parse::Source;
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new(r"(\d+)-(\d+)").unwrap();
+    /// let input = "2-4,6-8";
+    ///
+    /// assert_eq!(["2", "4"], Source(input).groups(input, &re).unwrap());
+    /// assert!(Source(input).groups::<2>("x", &re).is_err());
+    /// ```
+    pub fn groups<const N: usize>(
+        &self,
+        part: &'a str,
+        regex: &Regex,
+    ) -> Result<[&'a str; N], ParseError> {
+        let caps = self.captures(part, regex)?;
+
+        Ok(std::array::from_fn(|i| {
+            caps.get(i + 1).map_or("", |m| m.as_str())
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, Source};
+    use regex::Regex;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, 1, 1, "1 2")]
+    #[case(2, 1, 3, "1 2")]
+    #[case(4, 2, 1, "")]
+    #[case(5, 3, 1, "34\t5")]
+    #[case(9, 3, 5, "34\t5")]
+    fn validate_location(
+        #[case] offset: usize,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] text: &str,
+    ) {
+        let input = "1 2\n\n34\t5\r\n";
+        let error = ParseError::at(input, &input[offset..], "something");
+
+        assert_eq!(
+            (line, column, text),
+            (error.line, error.column, &*error.text)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let input = "Sensor at x=2, y=18\nSensor at x=9, y=1x6";
+        let source = Source(input);
+        let (_, y) = source.split_once(&input[20..], "y=").unwrap();
+
+        assert_eq!(
+            "Invalid input on line 2, column 18: expected a number\n2 |".to_string()
+                + " Sensor at x=9, y=1x6\n  |                  ^",
+            source.parse::<u32>(y, "a number").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_missing() {
+        let input = "R 4\nU";
+        let source = Source(input);
+        let error = source.split_once(&input[4..], " ").unwrap_err();
+
+        assert_eq!((2, 2), (error.line, error.column));
+        assert_eq!("' '", error.expected);
+
+        let regex = Regex::new(r"^\w \d+$").unwrap();
+        assert!(source.captures(&input[..3], &regex).is_ok());
+
+        let error = source.captures(&input[4..], &regex).unwrap_err();
+        assert_eq!((2, 1), (error.line, error.column));
+        assert_eq!(r"a line matching '^\w \d+$'", error.expected);
+
+        // text that isn't part of the input is reported at the start
+        let error = source.error("elsewhere", "x");
+        assert_eq!((1, 1), (error.line, error.column));
+    }
+}
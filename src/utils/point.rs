@@ -5,6 +5,17 @@ use std::{
 
 use itertools::Itertools;
 
+/// A point in a discrete space with any number of dimensions
+///
+/// ```
+/// use aoc2022::Pt;
+///
+/// let pt = Pt([1, -2]) + Pt([3, 4]) * 2;
+/// assert_eq!(Pt([7, 6]), pt);
+/// assert_eq!(13, pt.mag());
+/// assert_eq!(4, Pt::<2>::card_offsets().len());
+/// assert_eq!(26, Pt::<3>::neighbour_offsets().len());
+/// ```
 #[derive(Hash, PartialEq, Eq, PartialOrd, Debug, Clone, Copy)]
 pub struct Pt<const DIMS: usize>(pub [isize; DIMS]);
 
@@ -87,7 +98,6 @@ impl<const DIMS: usize> Mul<isize> for Pt<DIMS> {
     }
 }
 
-#[allow(dead_code)]
 impl<const DIMS: usize> Pt<DIMS> {
     /// get all the offsets required to get every neighbour to a position
     pub fn neighbour_offsets() -> HashSet<Pt<DIMS>> {
@@ -112,16 +122,114 @@ impl<const DIMS: usize> Pt<DIMS> {
         pts.into_iter().map(Pt).collect()
     }
 
+    /// the manhattan distance from the origin
     pub fn mag(&self) -> isize {
         self.0.iter().map(|v| v.abs()).sum()
     }
+
+    /// rotate about the origin by `turns` quarter turns in the plane of two axes. Each turn moves the `from` axis onto the `to` axis,
+    /// so in 2D with y increasing downwards, `rotate(0, 1, 1)` turns clockwise. Negative turns go the other way
+    ///
+    /// ```
+    /// use aoc2022::Pt;
+    ///
+    /// assert_eq!(Pt([0, 1]), Pt([1, 0]).rotate(0, 1, 1));
+    /// assert_eq!(Pt([-2, 1]), Pt([1, 2]).rotate(0, 1, 1));
+    /// assert_eq!(Pt([1, 2]), Pt([1, 2]).rotate(0, 1, -4));
+    /// assert_eq!(Pt([1, -3, 2]), Pt([1, 2, 3]).rotate(1, 2, 1));
+    /// ```
+    pub fn rotate(mut self, from: usize, to: usize, turns: isize) -> Self {
+        for _ in 0..turns.rem_euclid(4) {
+            let (a, b) = (self.0[from], self.0[to]);
+            self.0[from] = -b;
+            self.0[to] = a;
+        }
+        self
+    }
+
+    /// reflect across the plane where `axis` is 0, by negating that co-ordinate
+    pub fn reflect(mut self, axis: usize) -> Self {
+        self.0[axis] *= -1;
+        self
+    }
+
+    /// this point in every orientation, including reflections. See `Orientation::all`
+    pub fn orientations(self) -> impl Iterator<Item = Pt<DIMS>> {
+        Orientation::all().map(move |o| o.apply(&self))
+    }
+}
+
+/// A rotation or reflection about the origin that maps axes onto axes, as a signed permutation of the co-ordinates.
+/// Handy for matching things up when we don't know which way round they are
+///
+/// ```
+/// use aoc2022::utils::point::Orientation;
+/// use aoc2022::Pt;
+///
+/// let flip_x = Orientation { axes: [0, 1], signs: [-1, 1] };
+/// assert_eq!(Pt([-1, 2]), flip_x.apply(&Pt([1, 2])));
+/// assert!(!flip_x.is_rotation());
+///
+/// assert_eq!(8, Orientation::<2>::all().count());
+/// assert_eq!(24, Orientation::<3>::rotations().count());
+/// assert_eq!(48, Orientation::<3>::all().count());
+/// ```
+#[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Orientation<const DIMS: usize> {
+    /// which of the original co-ordinates ends up in each dimension
+    pub axes: [usize; DIMS],
+    /// 1, or -1 if the co-ordinate in that dimension is negated
+    pub signs: [isize; DIMS],
+}
+
+impl<const DIMS: usize> Default for Orientation<DIMS> {
+    fn default() -> Self {
+        Self {
+            axes: std::array::from_fn(|i| i),
+            signs: [1; DIMS],
+        }
+    }
+}
+
+impl<const DIMS: usize> Orientation<DIMS> {
+    /// move a point into this orientation
+    pub fn apply(&self, pt: &Pt<DIMS>) -> Pt<DIMS> {
+        Pt(std::array::from_fn(|i| pt.0[self.axes[i]] * self.signs[i]))
+    }
+
+    /// whether this keeps things the same handedness, rather than mirroring them
+    pub fn is_rotation(&self) -> bool {
+        let swaps = (0..DIMS)
+            .tuple_combinations()
+            .filter(|&(i, j)| self.axes[i] > self.axes[j])
+            .count();
+        let flips = self.signs.iter().filter(|s| **s < 0).count();
+
+        (swaps + flips) % 2 == 0
+    }
+
+    /// every orientation, including reflections: 8 in 2D and 48 in 3D
+    pub fn all() -> impl Iterator<Item = Orientation<DIMS>> {
+        (0..DIMS).permutations(DIMS).flat_map(|axes| {
+            let axes: [usize; DIMS] = axes.try_into().unwrap();
+            (0..1 << DIMS).map(move |flipped: usize| Orientation {
+                axes,
+                signs: std::array::from_fn(|i| if flipped >> i & 1 == 1 { -1 } else { 1 }),
+            })
+        })
+    }
+
+    /// every orientation that's a rotation, without reflections: 4 in 2D and 24 in 3D
+    pub fn rotations() -> impl Iterator<Item = Orientation<DIMS>> {
+        Self::all().filter(Self::is_rotation)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
-    use super::Pt;
+    use super::{Orientation, Pt};
     use rstest::rstest;
 
     #[test]
@@ -214,4 +322,56 @@ mod tests {
     fn validate_mul(#[case] expected: Pt<3>, #[case] a: Pt<3>, #[case] b: isize) {
         assert_eq!(expected, a * b)
     }
+
+    #[rstest]
+    #[case(0, 1, 1, [-2, 1, 3])]
+    #[case(0, 1, 2, [-1, -2, 3])]
+    #[case(0, 1, 3, [2, -1, 3])]
+    #[case(0, 1, -1, [2, -1, 3])]
+    #[case(1, 0, 1, [2, -1, 3])]
+    #[case(0, 2, 1, [-3, 2, 1])]
+    #[case(2, 1, 5, [1, 3, -2])]
+    fn validate_rotate(
+        #[case] from: usize,
+        #[case] to: usize,
+        #[case] turns: isize,
+        #[case] expected: [isize; 3],
+    ) {
+        let pt = Pt([1, 2, 3]);
+
+        assert_eq!(Pt(expected), pt.rotate(from, to, turns));
+        assert_eq!(pt.mag(), pt.rotate(from, to, turns).mag());
+    }
+
+    #[test]
+    fn test_orientations() {
+        // a point with distinct co-ordinates lands somewhere different in every orientation
+        let all: HashSet<Pt<3>> = Pt([1, 2, 3]).orientations().collect();
+        assert_eq!(48, all.len());
+        assert!(all.contains(&Pt([-3, 1, -2])));
+
+        // the rotations are exactly what we can reach with quarter turns
+        let mut reachable = HashSet::from([Pt([1, 2, 3])]);
+        let mut to_visit = vec![Pt([1, 2, 3])];
+        while let Some(pt) = to_visit.pop() {
+            for (from, to) in [(0, 1), (1, 2), (2, 0)] {
+                let next = pt.rotate(from, to, 1);
+                if reachable.insert(next) {
+                    to_visit.push(next);
+                }
+            }
+        }
+        let rotated: HashSet<Pt<3>> = Orientation::rotations()
+            .map(|o| o.apply(&Pt([1, 2, 3])))
+            .collect();
+        assert_eq!(reachable, rotated);
+
+        // and anything else is a reflection of one of them
+        let reflected: HashSet<Pt<3>> = rotated.iter().map(|pt| pt.reflect(0)).collect();
+        assert!(reflected.is_disjoint(&rotated));
+        assert_eq!(all, &reflected | &rotated);
+
+        assert_eq!(Some(Orientation::default()), Orientation::<2>::all().next());
+        assert_eq!(4, Orientation::<2>::rotations().count());
+    }
 }
@@ -0,0 +1,245 @@
+//! Graph searches over any node type, with the graph given as a function from a node to its neighbours
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// What a search reached: how far each node is from the start, and which node it was reached from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<N: Hash + Eq> {
+    pub start: N,
+    pub distances: HashMap<N, usize>,
+    pub predecessors: HashMap<N, N>,
+}
+
+impl<N: Hash + Eq + Clone> SearchResult<N> {
+    fn new(start: N) -> Self {
+        Self {
+            distances: HashMap::from([(start.clone(), 0)]),
+            predecessors: HashMap::new(),
+            start,
+        }
+    }
+
+    /// how far a node is from the start, if the search reached it
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    /// the path from the start to a node, including both ends, if the search reached it
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// breadth-first search from `start`, where every step costs 1
+///
+/// ```
+/// use aoc2022::utils::search::bfs;
+///
+/// // each number leads to double it and one more, up to 20
+/// let result = bfs(1, |n| [n * 2, n + 1].into_iter().filter(|n| *n <= 20));
+///
+/// assert_eq!(Some(4), result.distance(&10));
+/// assert_eq!(Some(vec![1, 2, 4, 5, 10]), result.path(&10));
+/// ```
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> SearchResult<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new(start.clone());
+    let mut to_visit = VecDeque::from([start]);
+
+    while let Some(node) = to_visit.pop_front() {
+        let distance = result.distances[&node] + 1;
+
+        for next in neighbours(&node) {
+            if result.distances.contains_key(&next) {
+                continue;
+            }
+            result.distances.insert(next.clone(), distance);
+            result.predecessors.insert(next.clone(), node.clone());
+            to_visit.push_back(next);
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's search from `start`, where `neighbours` gives each neighbour along with the cost of stepping to it
+///
+/// ```
+/// use aoc2022::utils::search::dijkstra;
+///
+/// let edges = |n: &char| match n {
+///     'a' => vec![('b', 7), ('c', 2)],
+///     'c' => vec![('b', 3)],
+///     _ => vec![],
+/// };
+/// let result = dijkstra('a', edges);
+///
+/// assert_eq!(Some(5), result.distance(&'b'));
+/// assert_eq!(Some(vec!['a', 'c', 'b']), result.path(&'b'));
+/// ```
+pub fn dijkstra<N, I>(start: N, neighbours: impl FnMut(&N) -> I) -> SearchResult<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    best_first(start, neighbours, |_| 0, |_| false)
+}
+
+/// A* search from `start` to `goal`, guided by a `heuristic` that must never overestimate the distance to the goal.
+/// The search stops once it reaches the goal, so only the nodes it explored are in the result
+///
+/// ```
+/// use aoc2022::{utils::search::astar, Pt};
+///
+/// let steps = |pt: &Pt<2>| Pt::<2>::card_offsets().into_iter().map(|off| (pt + &off, 1)).collect::<Vec<_>>();
+/// let result = astar(Pt([0, 0]), Pt([3, 4]), steps, |Pt([x, y])| (3 - x).unsigned_abs() + (4 - y).unsigned_abs());
+///
+/// assert_eq!(Some(7), result.distance(&Pt([3, 4])));
+/// assert_eq!(8, result.path(&Pt([3, 4])).unwrap().len());
+/// ```
+pub fn astar<N, I>(
+    start: N,
+    goal: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl Fn(&N) -> usize,
+) -> SearchResult<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    best_first(start, neighbours, heuristic, |node| *node == goal)
+}
+
+/// visit nodes in order of their distance plus the heuristic, until there are none left or one is the goal
+fn best_first<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    heuristic: impl Fn(&N) -> usize,
+    is_goal: impl Fn(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut result = SearchResult::new(start.clone());
+    // the heap holds indexes into `queued`, so nodes don't need to be `Ord`
+    let mut queued = vec![start.clone()];
+    let mut to_visit = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+
+    while let Some(Reverse((_, distance, i))) = to_visit.pop() {
+        let node = queued[i].clone();
+        // we've since found a shorter way here
+        if distance > result.distances[&node] {
+            continue;
+        }
+        if is_goal(&node) {
+            break;
+        }
+
+        for (next, cost) in neighbours(&node) {
+            let next_distance = distance + cost;
+            if result
+                .distance(&next)
+                .is_some_and(|known| known <= next_distance)
+            {
+                continue;
+            }
+
+            result.distances.insert(next.clone(), next_distance);
+            result.predecessors.insert(next.clone(), node.clone());
+            to_visit.push(Reverse((
+                next_distance + heuristic(&next),
+                next_distance,
+                queued.len(),
+            )));
+            queued.push(next);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+    use crate::utils::point::Pt;
+    use rstest::rstest;
+
+    /// a 5x5 room with a wall down the middle, open only at the bottom
+    fn open(Pt([x, y]): &Pt<2>) -> bool {
+        (0..5).contains(x) && (0..5).contains(y) && (*x != 2 || *y == 4)
+    }
+
+    fn steps(pt: &Pt<2>) -> Vec<Pt<2>> {
+        Pt::<2>::card_offsets()
+            .into_iter()
+            .map(|off| pt + &off)
+            .filter(open)
+            .collect()
+    }
+
+    #[rstest]
+    #[case(Pt([0, 0]), Some(0))]
+    #[case(Pt([1, 0]), Some(1))]
+    #[case(Pt([4, 0]), Some(12))]
+    #[case(Pt([2, 2]), None)]
+    fn validate_searches(#[case] to: Pt<2>, #[case] expected: Option<usize>) {
+        let by_bfs = bfs(Pt([0, 0]), steps);
+        let by_dijkstra = dijkstra(Pt([0, 0]), |pt| steps(pt).into_iter().map(|n| (n, 1)));
+        let by_astar = astar(
+            Pt([0, 0]),
+            to,
+            |pt| steps(pt).into_iter().map(|n| (n, 1)),
+            |pt| (to - *pt).mag() as usize,
+        );
+
+        for result in [by_bfs, by_dijkstra, by_astar] {
+            assert_eq!(expected, result.distance(&to));
+
+            match result.path(&to) {
+                None => assert_eq!(None, expected),
+                Some(path) => {
+                    assert_eq!(expected, Some(path.len() - 1));
+                    assert_eq!(Some(&Pt([0, 0])), path.first());
+                    assert_eq!(Some(&to), path.last());
+                    assert!(path.windows(2).all(|w| (w[1] - w[0]).mag() == 1));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_weighted() {
+        // the direct edge is more expensive than going the long way round
+        let edges = |n: &usize| match n {
+            0 => vec![(1, 10), (2, 1)],
+            2 => vec![(3, 1)],
+            3 => vec![(1, 1)],
+            _ => vec![],
+        };
+
+        let result = dijkstra(0, edges);
+        assert_eq!(Some(3), result.distance(&1));
+        assert_eq!(Some(vec![0, 2, 3, 1]), result.path(&1));
+
+        let result = astar(0, 1, edges, |_| 0);
+        assert_eq!(Some(vec![0, 2, 3, 1]), result.path(&1));
+    }
+}
@@ -1,86 +1,443 @@
-use anyhow::Result;
-use std::{fmt::Display, time::Instant};
+use anyhow::{anyhow, Result};
+use std::{
+    any::Any,
+    fmt::Display,
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
 
-////////////// SOLUTION LINEAR
-/// When a day has two parts that must be solved sequentially
-pub trait SolutionLinear<I, S1: Display, S2: Display> {
-    fn load(input: &str) -> Result<I>;
-    fn part1(input: &mut I) -> Result<S1>;
-    fn part2(input: &mut I, part_1_solution: S1) -> Result<S2>;
+use super::params::{ParamInfo, Params};
+
+////////////// SOLVE REPORT
+/// The answers and timings produced by solving a day. Parts that weren't solved have no answer
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SolveReport {
+    pub day: usize,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub parse_time: Duration,
+    pub part_1_time: Duration,
+    pub part_2_time: Duration,
+    /// true when both parts were solved in one step. The combined time is stored in `part_1_time`
+    pub solved_together: bool,
 }
 
-/// Solve a day where part 2 depends on the output of part 1.
-/// Returns the total time elapsed in milliseconds
-pub fn solve_linear<S: SolutionLinear<I, S1, S2>, I, S1: Display, S2: Display>(
+impl SolveReport {
+    /// total time spent parsing and solving
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.part_1_time + self.part_2_time
+    }
+}
+
+/// Which parts of a day to solve
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Parts {
+    #[default]
+    Both,
+    One,
+    Two,
+}
+
+impl Parts {
+    /// the parts to solve when given an optional part number. No part means both
+    pub fn from_part(part: Option<u8>) -> Result<Self> {
+        match part {
+            None => Ok(Parts::Both),
+            Some(1) => Ok(Parts::One),
+            Some(2) => Ok(Parts::Two),
+            Some(p) => Err(anyhow!("There is no part {}", p)),
+        }
+    }
+
+    pub fn includes_part_1(&self) -> bool {
+        matches!(self, Parts::Both | Parts::One)
+    }
+
+    pub fn includes_part_2(&self) -> bool {
+        matches!(self, Parts::Both | Parts::Two)
+    }
+
+    /// how many parts are solved
+    pub fn count(&self) -> usize {
+        match self {
+            Parts::Both => 2,
+            Parts::One | Parts::Two => 1,
+        }
+    }
+}
+
+////////////// SOLUTION
+/// A day's solution with its input type hidden, so every day can be run the same way.
+/// Implemented for `SolutionLinear` and `SolutionSimultaneous` days through `linear` and `simultaneous`
+pub trait Solution: Sync {
+    /// the params this day can be run with, and their defaults
+    fn params(&self) -> Vec<ParamInfo>;
+    /// parse the input into whatever state the parts need, using the given params
+    fn parse(&self, input: &str, params: &Params) -> Result<Box<dyn Any>>;
+    /// solve part 1 using the parsed state
+    fn part1(&self, parsed: &mut dyn Any) -> Result<String>;
+    /// solve part 2 using the parsed state, solving anything it depends on first
+    fn part2(&self, parsed: &mut dyn Any) -> Result<String>;
+    /// solve both parts in one step, for days where that's how they're solved.
+    /// Returns None if the parts should be solved one after the other
+    fn solve_both(&self, _parsed: &mut dyn Any) -> Option<Result<(String, String)>> {
+        None
+    }
+}
+
+/// marks which day and types an adapter is for, without owning any of them
+type Adapts<S, I, S1, S2> = PhantomData<fn() -> (S, I, S1, S2)>;
+
+/// get the state a solution parsed, as the type the solution expects
+fn state<T: 'static>(parsed: &mut dyn Any) -> Result<&mut T> {
+    parsed
+        .downcast_mut()
+        .ok_or_else(|| anyhow!("Parsed input was not parsed by this solution"))
+}
+
+/// Solve some or all of a day with any solution, with any params not in `params` taking their defaults.
+/// Returns the answers and time taken for each step
+pub fn solve(
+    solution: &dyn Solution,
     input: &str,
-) -> Result<f32> {
+    parts: Parts,
+    params: &Params,
+) -> Result<SolveReport> {
+    params.check(&solution.params())?;
+
     let start = Instant::now();
 
-    let mut input = S::load(input)?;
+    let mut parsed = solution.parse(input, params)?;
+
+    let mut report = SolveReport {
+        parse_time: start.elapsed(),
+        ..Default::default()
+    };
+
+    if parts == Parts::Both {
+        let start = Instant::now();
+
+        if let Some(answers) = solution.solve_both(parsed.as_mut()) {
+            let (p1, p2) = answers?;
+
+            return Ok(SolveReport {
+                part_1: Some(p1),
+                part_2: Some(p2),
+                part_1_time: start.elapsed(),
+                solved_together: true,
+                ..report
+            });
+        }
+    }
+
+    if parts.includes_part_1() {
+        let start = Instant::now();
+        report.part_1 = Some(solution.part1(parsed.as_mut())?);
+        report.part_1_time = start.elapsed();
+    }
+
+    if parts.includes_part_2() {
+        let start = Instant::now();
+        report.part_2 = Some(solution.part2(parsed.as_mut())?);
+        report.part_2_time = start.elapsed();
+    }
 
-    let input_loaded = start.elapsed().as_secs_f32() * 1000.0;
+    Ok(report)
+}
 
-    println!("Parsed input in:\t{}ms", input_loaded);
+////////////// SOLUTION LINEAR
+/// When a day has two parts that must be solved sequentially
+pub trait SolutionLinear<I, S1: Display, S2: Display> {
+    fn load(input: &str) -> Result<I>;
+    /// the params this day can be run with, and their defaults
+    fn params() -> Vec<ParamInfo> {
+        vec![]
+    }
+    /// load the input using the given params. Days with params override this and keep them in their input
+    fn load_with(input: &str, _params: &Params) -> Result<I> {
+        Self::load(input)
+    }
+    fn part1(input: &mut I) -> Result<S1>;
+    fn part2(input: &mut I, part_1_solution: S1) -> Result<S2>;
+}
 
-    let p1_start = Instant::now();
+/// Adapts a `SolutionLinear` day to `Solution`
+pub struct Linear<S, I, S1, S2>(Adapts<S, I, S1, S2>);
 
-    let p1 = S::part1(&mut input)?;
+/// Use a `SolutionLinear` day as a `Solution`
+pub const fn linear<S: SolutionLinear<I, S1, S2>, I, S1: Display, S2: Display>(
+) -> Linear<S, I, S1, S2> {
+    Linear(PhantomData)
+}
 
-    let p1_end = p1_start.elapsed().as_secs_f32() * 1000.0;
+/// a linear day's input, and its part 1 answer once it's been solved
+struct LinearState<I, S1> {
+    input: I,
+    part_1: Option<S1>,
+}
 
-    println!("Part 1 Solution: \t{}", p1);
-    println!("Part 1 solved in:\t{}ms", p1_end);
+impl<S, I, S1, S2> Solution for Linear<S, I, S1, S2>
+where
+    S: SolutionLinear<I, S1, S2>,
+    I: 'static,
+    S1: Display + 'static,
+    S2: Display,
+{
+    fn params(&self) -> Vec<ParamInfo> {
+        S::params()
+    }
 
-    let p2_start = Instant::now();
+    fn parse(&self, input: &str, params: &Params) -> Result<Box<dyn Any>> {
+        Ok(Box::new(LinearState::<I, S1> {
+            input: S::load_with(input, params)?,
+            part_1: None,
+        }))
+    }
 
-    let p2 = S::part2(&mut input, p1)?;
+    fn part1(&self, parsed: &mut dyn Any) -> Result<String> {
+        let state = state::<LinearState<I, S1>>(parsed)?;
+        let p1 = S::part1(&mut state.input)?;
+        let answer = p1.to_string();
 
-    let p2_end = p2_start.elapsed().as_secs_f32() * 1000.0;
+        state.part_1 = Some(p1);
 
-    println!("Part 2 Solution: \t{}", p2);
-    println!("Part 2 solved in:\t{}ms", p2_end);
+        Ok(answer)
+    }
 
-    let solved_in = input_loaded + p1_end + p2_end;
+    /// part 2 needs part 1's answer, so part 1 is solved first if it hasn't been already
+    fn part2(&self, parsed: &mut dyn Any) -> Result<String> {
+        let state = state::<LinearState<I, S1>>(parsed)?;
+        let p1 = match state.part_1.take() {
+            Some(p1) => p1,
+            None => S::part1(&mut state.input)?,
+        };
 
-    println!("Overall time:\t\t{}ms", solved_in);
+        Ok(S::part2(&mut state.input, p1)?.to_string())
+    }
+}
 
-    Ok(solved_in)
+/// Solve a day where part 2 depends on the output of part 1.
+/// Returns the answers and time taken for each step
+pub fn solve_linear<S, I, S1, S2>(input: &str) -> Result<SolveReport>
+where
+    S: SolutionLinear<I, S1, S2> + 'static,
+    I: 'static,
+    S1: Display + 'static,
+    S2: Display + 'static,
+{
+    solve(
+        &linear::<S, I, S1, S2>(),
+        input,
+        Parts::Both,
+        &Params::default(),
+    )
 }
 
 ////////////// SOLUTION SIMULTANEOUS
 /// When a day has two parts that can be solved simultaneously
 pub trait SolutionSimultaneous<I, S1: Display, S2: Display> {
     fn load(input: &str) -> Result<I>;
+    /// the params this day can be run with, and their defaults
+    fn params() -> Vec<ParamInfo> {
+        vec![]
+    }
+    /// load the input using the given params. Days with params override this and keep them in their input
+    fn load_with(input: &str, _params: &Params) -> Result<I> {
+        Self::load(input)
+    }
     fn solve(input: I) -> Result<(S1, S2)>;
 }
 
+/// Adapts a `SolutionSimultaneous` day to `Solution`
+pub struct Simultaneous<S, I, S1, S2>(Adapts<S, I, S1, S2>);
+
+/// Use a `SolutionSimultaneous` day as a `Solution`
+pub const fn simultaneous<S: SolutionSimultaneous<I, S1, S2>, I, S1: Display, S2: Display>(
+) -> Simultaneous<S, I, S1, S2> {
+    Simultaneous(PhantomData)
+}
+
+/// a simultaneous day's input until it's solved, then its answers
+struct SimultaneousState<I> {
+    input: Option<I>,
+    answers: Option<(String, String)>,
+}
+
+impl<I> SimultaneousState<I> {
+    /// solve both parts if they haven't been already
+    fn answers<S1: Display, S2: Display>(
+        &mut self,
+        solve: fn(I) -> Result<(S1, S2)>,
+    ) -> Result<(String, String)> {
+        if self.answers.is_none() {
+            let input = self
+                .input
+                .take()
+                .ok_or_else(|| anyhow!("Input was used up by a failed solve"))?;
+            let (p1, p2) = solve(input)?;
+            self.answers = Some((p1.to_string(), p2.to_string()));
+        }
+
+        Ok(self.answers.clone().unwrap())
+    }
+}
+
+/// both parts are solved together, so asking for either part solves both
+impl<S, I, S1, S2> Solution for Simultaneous<S, I, S1, S2>
+where
+    S: SolutionSimultaneous<I, S1, S2>,
+    I: 'static,
+    S1: Display,
+    S2: Display,
+{
+    fn params(&self) -> Vec<ParamInfo> {
+        S::params()
+    }
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Box<dyn Any>> {
+        Ok(Box::new(SimultaneousState {
+            input: Some(S::load_with(input, params)?),
+            answers: None,
+        }))
+    }
+
+    fn part1(&self, parsed: &mut dyn Any) -> Result<String> {
+        Ok(state::<SimultaneousState<I>>(parsed)?.answers(S::solve)?.0)
+    }
+
+    fn part2(&self, parsed: &mut dyn Any) -> Result<String> {
+        Ok(state::<SimultaneousState<I>>(parsed)?.answers(S::solve)?.1)
+    }
+
+    fn solve_both(&self, parsed: &mut dyn Any) -> Option<Result<(String, String)>> {
+        Some(state::<SimultaneousState<I>>(parsed).and_then(|state| state.answers(S::solve)))
+    }
+}
+
 /// Solve a day where part 1 and part 2 can be solved simultaneously
-/// Returns the total time elapsed in milliseconds
-pub fn solve_simultaneous<S: SolutionSimultaneous<I, S1, S2>, I, S1: Display, S2: Display>(
-    input: &str,
-) -> Result<f32> {
-    let start = Instant::now();
+/// Returns the answers and time taken for each step
+///
+/// ```
+/// use aoc2022::{solve_simultaneous, SolutionSimultaneous};
+///
+/// struct MinMax {}
+///
+/// impl SolutionSimultaneous<Vec<i32>, i32, i32> for MinMax {
+///     fn load(input: &str) -> anyhow::Result<Vec<i32>> {
+///         Ok(input.split(',').map(|n| n.parse().unwrap()).collect())
+///     }
+///
+///     fn solve(input: Vec<i32>) -> anyhow::Result<(i32, i32)> {
+///         Ok((*input.iter().min().unwrap(), *input.iter().max().unwrap()))
+///     }
+/// }
+///
+/// let report = solve_simultaneous::<MinMax, _, _, _>("4,-2,9").unwrap();
+/// assert_eq!(Some("-2".to_string()), report.part_1);
+/// assert_eq!(Some("9".to_string()), report.part_2);
+/// assert!(report.solved_together);
+/// ```
+pub fn solve_simultaneous<S, I, S1, S2>(input: &str) -> Result<SolveReport>
+where
+    S: SolutionSimultaneous<I, S1, S2> + 'static,
+    I: 'static,
+    S1: Display + 'static,
+    S2: Display + 'static,
+{
+    solve(
+        &simultaneous::<S, I, S1, S2>(),
+        input,
+        Parts::Both,
+        &Params::default(),
+    )
+}
 
-    let input = S::load(input)?;
+#[cfg(test)]
+mod tests {
+    use super::{linear, simultaneous, solve, solve_linear, solve_simultaneous, Parts};
+    use crate::solutions::templates::{
+        linear_template::ExampleSolutionLinear, simultaneous_template::ExampleSolutionSimultaneous,
+    };
+    use crate::utils::params::Params;
+    use rstest::rstest;
 
-    let input_loaded = start.elapsed().as_secs_f32() * 1000.0;
+    fn answers(part_1: &str, part_2: &str) -> (Option<String>, Option<String>) {
+        (Some(part_1.to_string()), Some(part_2.to_string()))
+    }
 
-    println!("Parsed input in:\t{}ms", input_loaded);
+    #[test]
+    fn test_reports() {
+        let linear = solve_linear::<ExampleSolutionLinear, _, _, _>("[1,2,3]").unwrap();
+        assert_eq!(answers("6", "18"), (linear.part_1, linear.part_2));
+        assert!(!linear.solved_together);
 
-    let start_solving = Instant::now();
+        let simul =
+            solve_simultaneous::<ExampleSolutionSimultaneous, _, _, _>("[6,5,4,2,3,5,8]").unwrap();
+        assert!(simul.solved_together);
+        assert_eq!(simul.total_time(), simul.parse_time + simul.part_1_time);
+        assert_eq!(answers("3", "5"), (simul.part_1, simul.part_2));
+    }
 
-    let (p1, p2) = S::solve(input)?;
+    #[rstest]
+    #[case(Parts::One, Some("6"), None)]
+    #[case(Parts::Two, None, Some("18"))]
+    #[case(Parts::Both, Some("6"), Some("18"))]
+    fn validate_linear_parts(
+        #[case] parts: Parts,
+        #[case] part_1: Option<&str>,
+        #[case] part_2: Option<&str>,
+    ) {
+        let report = solve(
+            &linear::<ExampleSolutionLinear, _, _, _>(),
+            "[1,2,3]",
+            parts,
+            &Params::default(),
+        )
+        .unwrap();
 
-    let solved = start_solving.elapsed().as_secs_f32() * 1000.0;
+        assert_eq!(part_1.map(str::to_string), report.part_1);
+        assert_eq!(part_2.map(str::to_string), report.part_2);
+        assert!(!report.solved_together);
+    }
 
-    println!("Part 1 Solution: \t{}", p1);
-    println!("Part 2 Solution: \t{}", p2);
+    #[rstest]
+    #[case(Parts::One, Some("3"), None)]
+    #[case(Parts::Two, None, Some("5"))]
+    fn validate_simultaneous_parts(
+        #[case] parts: Parts,
+        #[case] part_1: Option<&str>,
+        #[case] part_2: Option<&str>,
+    ) {
+        let report = solve(
+            &simultaneous::<ExampleSolutionSimultaneous, _, _, _>(),
+            "[6,5,4,2,3,5,8]",
+            parts,
+            &Params::default(),
+        )
+        .unwrap();
 
-    println!("Solved in:\t\t{}ms", solved);
+        assert_eq!(part_1.map(str::to_string), report.part_1);
+        assert_eq!(part_2.map(str::to_string), report.part_2);
+        assert!(!report.solved_together);
+    }
 
-    let solved_in = input_loaded + solved;
+    #[test]
+    fn test_unknown_param() {
+        let params = Params::parse(&["target=10"]).unwrap();
+        let result = solve(
+            &linear::<ExampleSolutionLinear, _, _, _>(),
+            "[1,2,3]",
+            Parts::Both,
+            &params,
+        );
 
-    println!("Overall time:\t\t{}ms", solved_in);
+        assert!(result.is_err());
+    }
 
-    Ok(solved_in)
+    #[test]
+    fn test_parts() {
+        assert_eq!(Parts::Both, Parts::from_part(None).unwrap());
+        assert_eq!(Parts::Two, Parts::from_part(Some(2)).unwrap());
+        assert!(Parts::from_part(Some(3)).is_err());
+    }
 }